@@ -0,0 +1,2 @@
+// The cap key placed on every cell of a layout, null for cells left empty
+export type Assignment = Array<number | null>
@@ -0,0 +1,11 @@
+import { RGB } from './RGB'
+
+export interface Cap {
+  key: number;
+  image: string;
+  name: string;
+  amount: number;
+  color: RGB;
+  // In millimeters, caps without one are regular 26 mm crown caps
+  diameter?: number;
+}
@@ -0,0 +1,12 @@
+// Cap counts per cap key
+export type CapCounts = Record<number, number>
+
+export interface Inventory {
+  version: number;
+  // Caps in the box, consumed caps already taken off
+  stock: CapCounts;
+  // Caps set aside for projects not built yet, per project id
+  reservations: Record<string, CapCounts>;
+  // Caps glued down so far, per project id
+  consumed: Record<string, CapCounts>;
+}
@@ -0,0 +1,5 @@
+export interface Lab {
+  l: number;
+  a: number;
+  b: number;
+}
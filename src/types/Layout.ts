@@ -0,0 +1,22 @@
+// All coordinates and sizes are in millimeters, measured from the top left of the board
+export interface CapPosition {
+  x: number;
+  y: number;
+  row: number;
+  col: number;
+  diameter: number;
+}
+
+export interface Layout {
+  width: number;
+  height: number;
+  positions: Array<CapPosition>;
+  neighbors: Array<Array<number>>;
+}
+
+export interface GridLayout extends Layout {
+  rows: number;
+  cols: number;
+}
+
+export type Packing = 'square' | 'hex'
@@ -0,0 +1,18 @@
+export type AssemblyOrder = 'rows' | 'spiral' | 'color'
+
+// From the bottom left or the center y is measured upwards, as CNC machines do
+export type Origin = 'topLeft' | 'bottomLeft' | 'center'
+
+// A cap as it is put on the board, coordinates in millimeters from the chosen origin
+export interface Placement {
+  step: number;
+  cell: number;
+  row: number;
+  col: number;
+  x: number;
+  y: number;
+  diameter: number;
+  capKey: number | null;
+  capName: string;
+  panel?: number;
+}
@@ -0,0 +1,39 @@
+import { Assignment } from './Assignment'
+import { Packing } from './Layout'
+import { CapLibrary } from '../utils/capLibrary'
+import { Symmetry } from '../utils/symmetry'
+
+// Everything needed to rebuild the exact same layout, sizes in millimeters
+export type GridSpec = {
+  boardWidth: number;
+  boardHeight: number;
+  capDiameter: number;
+  gap: number;
+} & (
+  { kind: 'square' | 'hex' | 'spiral' | 'rings' } |
+  { kind: 'ellipse'; packing: Packing } |
+  { kind: 'rotated'; packing: Packing; angle: number } |
+  { kind: 'organic'; method: 'jitter' | 'blueNoise'; jitter: number; seed: number }
+)
+
+export interface StencilSpec {
+  // Data url of the mask image
+  mask: string;
+  capKeys: Array<number>;
+  invert?: boolean;
+}
+
+export interface Constraints {
+  stencils: Array<StencilSpec>;
+  symmetry?: Symmetry;
+}
+
+export interface Project {
+  version: number;
+  capLibrary: CapLibrary;
+  uploadImage?: string;
+  desiredRatio: number;
+  grid?: GridSpec;
+  constraints: Constraints;
+  assignment?: Assignment;
+}
@@ -0,0 +1,47 @@
+import { Assignment } from '../types/Assignment'
+import { Layout } from '../types/Layout'
+
+export interface AdjacencyStatistics {
+  // Pairs of neighboring cells that both hold a cap
+  pairs: number;
+  // Of those, pairs holding the same cap type
+  samePairs: number;
+  // The largest group of touching cells holding the same cap type, undefined for an empty board
+  largestBlob?: {
+    capKey: number;
+    cells: Array<number>;
+  };
+}
+
+// How much the same caps clump together, to judge whether the mosaic needs more variation
+export default (layout: Layout, assignment: Assignment): AdjacencyStatistics => {
+  const statistics: AdjacencyStatistics = { pairs: 0, samePairs: 0 }
+  layout.neighbors.forEach((neighbors, cell) => {
+    const key = assignment[cell]
+    if (key === null || key === undefined) return
+    neighbors.forEach((neighbor) => {
+      if (neighbor <= cell || assignment[neighbor] === null || assignment[neighbor] === undefined) return
+      statistics.pairs++
+      if (assignment[neighbor] === key) statistics.samePairs++
+    })
+  })
+
+  const visited = new Array(layout.positions.length).fill(false)
+  layout.positions.forEach((_, start) => {
+    const capKey = assignment[start]
+    if (visited[start] || capKey === null || capKey === undefined) return
+    const cells = [start]
+    visited[start] = true
+    for (let i = 0; i < cells.length; i++) {
+      layout.neighbors[cells[i]].forEach((neighbor) => {
+        if (visited[neighbor] || assignment[neighbor] !== capKey) return
+        visited[neighbor] = true
+        cells.push(neighbor)
+      })
+    }
+    if (!statistics.largestBlob || cells.length > statistics.largestBlob.cells.length) {
+      statistics.largestBlob = { capKey, cells }
+    }
+  })
+  return statistics
+}
@@ -0,0 +1,71 @@
+import { Layout } from '../types/Layout'
+
+interface Options {
+  // Strengths from 0 (off) to 1
+  shadow: number;
+  rim: number;
+  occlusion: number;
+  // Direction the light comes from in degrees, 0 is from the right and 90 from the top
+  lightAngle: number;
+}
+
+// Makes a flat render look like the assembled board: caps cast a drop shadow, darken towards their rim and
+// the gaps between packed caps get darker. Works in place on a render of the whole board
+export default (image: ImageData, layout: Layout, options = {
+  shadow: 0.35,
+  rim: 0.25,
+  occlusion: 0.3,
+  lightAngle: 135
+} as Options): ImageData => {
+  const { data, width, height } = image
+  const scaleX = width / layout.width
+  const scaleY = height / layout.height
+  const scale = (scaleX + scaleY) / 2
+  const coverage = new Float32Array(width * height)
+  const darkness = new Float32Array(width * height)
+  const lightX = Math.cos(options.lightAngle * Math.PI / 180)
+  const lightY = -Math.sin(options.lightAngle * Math.PI / 180)
+
+  layout.positions.forEach(({ x, y, diameter }) => {
+    const cx = x * scaleX
+    const cy = y * scaleY
+    const r = diameter / 2 * scale
+    // Shadows fall away from the light and are blurred over a fraction of the cap
+    const sx = cx - lightX * r * 0.08
+    const sy = cy - lightY * r * 0.08
+    const blur = Math.max(r * 0.15, 1)
+    const reach = r * 0.3 + r * 0.08 + blur
+
+    for (let py = Math.max(Math.floor(cy - r - reach), 0); py < Math.min(Math.ceil(cy + r + reach), height); py++) {
+      for (let px = Math.max(Math.floor(cx - r - reach), 0); px < Math.min(Math.ceil(cx + r + reach), width); px++) {
+        const i = py * width + px
+        const rim = Math.hypot(px + 0.5 - cx, py + 0.5 - cy) - r
+        const inside = Math.min(Math.max(0.5 - rim, 0), 1)
+
+        if (inside > 0) {
+          const t = Math.min(Math.max((rim + r * 0.2) / (r * 0.2), 0), 1)
+          const factor = 1 - options.rim * t * t * inside
+          data[i * 4] *= factor
+          data[i * 4 + 1] *= factor
+          data[i * 4 + 2] *= factor
+          coverage[i] = Math.max(coverage[i], inside)
+        }
+
+        const shadowRim = Math.hypot(px + 0.5 - sx, py + 0.5 - sy) - r
+        const shadow = options.shadow * Math.min(Math.max(1 - shadowRim / blur, 0), 1)
+        const occlusion = options.occlusion * Math.min(Math.max(1 - rim / (r * 0.3), 0), 1)
+        darkness[i] = Math.max(darkness[i], shadow, occlusion)
+      }
+    }
+  })
+
+  // Shadows and occlusion only darken what is not covered by a cap
+  for (let i = 0; i < darkness.length; i++) {
+    const factor = 1 - darkness[i] * (1 - coverage[i])
+    data[i * 4] *= factor
+    data[i * 4 + 1] *= factor
+    data[i * 4 + 2] *= factor
+  }
+
+  return image
+}
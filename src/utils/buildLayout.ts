@@ -0,0 +1,21 @@
+import { Layout } from '../types/Layout'
+import { GridSpec } from '../types/Project'
+import ellipseLayout from './ellipseLayout'
+import hexGrid from './hexGrid'
+import organicLayout from './organicLayout'
+import { ringLayout, spiralLayout } from './radialLayout'
+import { rotatedGrid } from './rotatedGrid'
+import squareGrid from './squareGrid'
+
+export default (grid: GridSpec): Layout => {
+  const { boardWidth, boardHeight, capDiameter, gap } = grid
+  switch (grid.kind) {
+    case 'square': return squareGrid(boardWidth, boardHeight, capDiameter, gap)
+    case 'hex': return hexGrid(boardWidth, boardHeight, capDiameter, gap)
+    case 'spiral': return spiralLayout(boardWidth, boardHeight, capDiameter, gap)
+    case 'rings': return ringLayout(boardWidth, boardHeight, capDiameter, gap)
+    case 'ellipse': return ellipseLayout(boardWidth, boardHeight, capDiameter, gap, grid.packing)
+    case 'rotated': return rotatedGrid(boardWidth, boardHeight, capDiameter, grid.angle, gap, grid.packing)
+    case 'organic': return organicLayout(boardWidth, boardHeight, capDiameter, { method: grid.method, jitter: grid.jitter, seed: grid.seed, gap })
+  }
+}
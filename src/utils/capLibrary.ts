@@ -0,0 +1,55 @@
+import { Cap } from '../types/Cap'
+import getAverageColor from './getAverageColor'
+import throwIfAborted from './throwIfAborted'
+
+// Bump whenever the way cap colors are extracted from their photos changes
+export const CAP_LIBRARY_VERSION = 1
+
+export interface CapLibraryEntry extends Cap {
+  imageHash: string;
+}
+
+export interface CapLibrary {
+  version: number;
+  caps: Array<CapLibraryEntry>;
+}
+
+// FNV-1a, only used to notice changed photos, not for security
+export function hashString (value: string): string {
+  let hash = 0x811c9dc5
+  for (let i = 0; i < value.length; i++) {
+    hash ^= value.charCodeAt(i)
+    hash = Math.imul(hash, 0x01000193)
+  }
+  return (hash >>> 0).toString(16).padStart(8, '0')
+}
+
+export function serializeCapLibrary (caps: Array<Cap>): CapLibrary {
+  return {
+    version: CAP_LIBRARY_VERSION,
+    caps: caps.map((cap) => ({ ...cap, imageHash: hashString(cap.image) }))
+  }
+}
+
+export function isCapLibraryStale (library: Partial<CapLibrary>): boolean {
+  return library.version !== CAP_LIBRARY_VERSION
+}
+
+export function getStaleCaps (library: Partial<CapLibrary>): Array<Cap> {
+  const caps = (library.caps || []) as Array<Partial<CapLibraryEntry> & Cap>
+  if (isCapLibraryStale(library)) return caps
+  return caps.filter((cap) => cap.imageHash !== hashString(cap.image))
+}
+
+// Recomputes the colors of only those caps whose photo changed since the library was saved
+export async function refreshCapLibrary (library: Partial<CapLibrary>, signal?: AbortSignal): Promise<CapLibrary> {
+  const staleCaps = getStaleCaps(library).filter((cap) => cap.image)
+  // One cap at a time, so cancelling leaves no cap half way and the rest untouched
+  for (const cap of staleCaps) {
+    throwIfAborted(signal)
+    const color = await getAverageColor(cap.image)
+    throwIfAborted(signal)
+    cap.color = color
+  }
+  return serializeCapLibrary(library.caps || [])
+}
@@ -0,0 +1,54 @@
+import { Lab } from '../types/Lab'
+import { RGB } from '../types/RGB'
+
+// D65 reference white
+const xn = 0.95047
+const yn = 1
+const zn = 1.08883
+
+export function toLinear (channel: number): number {
+  const c = channel / 255
+  return c <= 0.04045 ? c / 12.92 : Math.pow((c + 0.055) / 1.055, 2.4)
+}
+
+export function fromLinear (channel: number): number {
+  const c = channel <= 0.0031308 ? channel * 12.92 : 1.055 * Math.pow(channel, 1 / 2.4) - 0.055
+  return Math.min(255, Math.max(0, Math.round(c * 255)))
+}
+
+function f (t: number): number {
+  return t > 216 / 24389 ? Math.cbrt(t) : (24389 / 27 * t + 16) / 116
+}
+
+function fInverse (t: number): number {
+  return t * t * t > 216 / 24389 ? t * t * t : (116 * t - 16) / (24389 / 27)
+}
+
+export function rgbToLab ({ r, g, b }: RGB): Lab {
+  const lr = toLinear(r)
+  const lg = toLinear(g)
+  const lb = toLinear(b)
+
+  const fx = f((0.4124 * lr + 0.3576 * lg + 0.1805 * lb) / xn)
+  const fy = f((0.2126 * lr + 0.7152 * lg + 0.0722 * lb) / yn)
+  const fz = f((0.0193 * lr + 0.1192 * lg + 0.9505 * lb) / zn)
+
+  return {
+    l: 116 * fy - 16,
+    a: 500 * (fx - fy),
+    b: 200 * (fy - fz)
+  }
+}
+
+export function labToRgb ({ l, a, b }: Lab): RGB {
+  const fy = (l + 16) / 116
+  const x = fInverse(fy + a / 500) * xn
+  const y = fInverse(fy) * yn
+  const z = fInverse(fy - b / 200) * zn
+
+  return {
+    r: fromLinear(3.2406 * x - 1.5372 * y - 0.4986 * z),
+    g: fromLinear(-0.9689 * x + 1.8758 * y + 0.0415 * z),
+    b: fromLinear(0.0557 * x - 0.2040 * y + 1.0570 * z)
+  }
+}
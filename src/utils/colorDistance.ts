@@ -0,0 +1,24 @@
+import { Assignment } from '../types/Assignment'
+import { Cap } from '../types/Cap'
+import { Lab } from '../types/Lab'
+import { RGB } from '../types/RGB'
+import { rgbToLab } from './colorConversion'
+
+// CIE76 color difference, around 2.3 being just noticeable
+export function deltaE (a: RGB, b: RGB): number {
+  return labDistance(rgbToLab(a), rgbToLab(b))
+}
+
+// The same for colors already converted to Lab, for comparing many cells against every cap
+export function labDistance (a: Lab, b: Lab): number {
+  return Math.sqrt((a.l - b.l) ** 2 + (a.a - b.a) ** 2 + (a.b - b.b) ** 2)
+}
+
+// The ΔE between every cell of the image and the cap placed on it, null for empty cells
+export function getCellCosts (cellColors: Array<RGB>, assignment: Assignment, caps: Array<Cap>): Array<number | null> {
+  const colors = new Map(caps.map((cap) => [cap.key, cap.color]))
+  return assignment.map((key, cell) => {
+    const color = key === null ? undefined : colors.get(key)
+    return color ? deltaE(cellColors[cell], color) : null
+  })
+}
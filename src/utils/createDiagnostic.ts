@@ -0,0 +1,44 @@
+import { Assignment } from '../types/Assignment'
+import { Cap } from '../types/Cap'
+import { Layout } from '../types/Layout'
+import { CAP_LIBRARY_VERSION, hashString } from './capLibrary'
+import { PROJECT_VERSION } from './project'
+
+interface Context {
+  // What was running, say 'renderMosaic'
+  stage: string;
+  layout?: Layout;
+  assignment?: Assignment;
+  caps?: Array<Cap>;
+  options?: object;
+  // How far the work got, for example the number of strips or tiles done
+  progress?: number;
+}
+
+// A small JSON report of a failed or cancelled run to attach to bug reports. Images are left out and only hashed,
+// so the same inputs can be recognised without sharing them
+export default (error: unknown, context: Context): string => {
+  const { stage, layout, assignment, caps, options, progress } = context
+  const inputs = JSON.stringify({
+    layout: layout && layout.positions,
+    assignment,
+    caps: caps && caps.map(({ key, color, amount, diameter }) => ({ key, color, amount, diameter }))
+  })
+
+  return JSON.stringify({
+    projectVersion: PROJECT_VERSION,
+    capLibraryVersion: CAP_LIBRARY_VERSION,
+    stage,
+    error: error instanceof Error
+      ? { name: error.name, message: error.message }
+      : { name: 'Error', message: String(error) },
+    aborted: error instanceof DOMException && error.name === 'AbortError',
+    layout: layout && { width: layout.width, height: layout.height, cells: layout.positions.length },
+    caps: caps && caps.length,
+    capImages: caps && hashString(caps.map((cap) => cap.image).join()),
+    emptyCells: assignment && assignment.filter((key) => key === null).length,
+    options,
+    progress,
+    checksum: hashString(inputs)
+  })
+}
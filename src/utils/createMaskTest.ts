@@ -0,0 +1,14 @@
+// Tells whether a point in millimeters is inside the mask, the mask being stretched over the board.
+// By default bright opaque pixels are inside
+export default (mask: HTMLCanvasElement, boardWidth: number, boardHeight: number, invert = false): (x: number, y: number) => boolean => {
+  const { width, height } = mask
+  const data = (mask.getContext('2d') as CanvasRenderingContext2D).getImageData(0, 0, width, height).data
+
+  return (x: number, y: number): boolean => {
+    const px = Math.min(Math.max(Math.floor(x / boardWidth * width), 0), width - 1)
+    const py = Math.min(Math.max(Math.floor(y / boardHeight * height), 0), height - 1)
+    const i = (py * width + px) * 4
+    const bright = data[i + 3] > 127 && (data[i] + data[i + 1] + data[i + 2]) / 3 > 127
+    return bright !== invert
+  }
+}
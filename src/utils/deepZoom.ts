@@ -0,0 +1,72 @@
+import { Assignment } from '../types/Assignment'
+import { Cap } from '../types/Cap'
+import { Layout } from '../types/Layout'
+import { renderFlatRegion } from './renderFlatCircles'
+import throwIfAborted from './throwIfAborted'
+
+interface Options {
+  tileSize: number;
+  overlap: number;
+  format: 'png' | 'jpeg';
+}
+
+export type TileHandler = (tile: ImageData, level: number, col: number, row: number) => void | Promise<void>
+
+// Deep Zoom (DZI) descriptor for OpenSeadragon style viewers, the tiles being stored as `<level>/<col>_<row>.<format>`
+export function deepZoomDescriptor (width: number, height: number, options: Options): string {
+  return '<?xml version="1.0" encoding="UTF-8"?>\n' +
+    `<Image xmlns="http://schemas.microsoft.com/deepzoom/2008" TileSize="${options.tileSize}" Overlap="${options.overlap}" Format="${options.format === 'jpeg' ? 'jpg' : 'png'}">\n` +
+    `  <Size Width="${width}" Height="${height}"/>\n` +
+    '</Image>\n'
+}
+
+// Renders every tile of every level of the pyramid, each level redrawn from the layout instead of scaled down,
+// and hands them to `onTile`. Resolves to the DZI descriptor
+export async function renderDeepZoom (layout: Layout, assignment: Assignment, caps: Array<Cap>, width: number, height: number, onTile: TileHandler, options = {
+  tileSize: 254,
+  overlap: 1,
+  format: 'png'
+} as Options, signal?: AbortSignal): Promise<string> {
+  const maxLevel = Math.ceil(Math.log2(Math.max(width, height, 1)))
+  const { tileSize, overlap } = options
+
+  for (let level = maxLevel; level >= 0; level--) {
+    const levelWidth = Math.max(Math.ceil(width / 2 ** (maxLevel - level)), 1)
+    const levelHeight = Math.max(Math.ceil(height / 2 ** (maxLevel - level)), 1)
+    const cols = Math.ceil(levelWidth / tileSize)
+    const rows = Math.ceil(levelHeight / tileSize)
+    const scaleX = levelWidth / layout.width
+    const scaleY = levelHeight / layout.height
+    const scale = (scaleX + scaleY) / 2
+
+    // Every cap goes to all the tiles it overlaps, overlap included
+    const tiles: Array<Array<number>> = Array.from({ length: cols * rows }, () => [])
+    layout.positions.forEach(({ x, y, diameter }, cell) => {
+      const r = diameter / 2 * scale + overlap
+      const firstCol = Math.max(Math.floor((x * scaleX - r) / tileSize), 0)
+      const lastCol = Math.min(Math.floor((x * scaleX + r) / tileSize), cols - 1)
+      const firstRow = Math.max(Math.floor((y * scaleY - r) / tileSize), 0)
+      const lastRow = Math.min(Math.floor((y * scaleY + r) / tileSize), rows - 1)
+      for (let row = firstRow; row <= lastRow; row++) {
+        for (let col = firstCol; col <= lastCol; col++) tiles[row * cols + col].push(cell)
+      }
+    })
+
+    for (let row = 0; row < rows; row++) {
+      for (let col = 0; col < cols; col++) {
+        throwIfAborted(signal)
+        const left = Math.max(col * tileSize - overlap, 0)
+        const top = Math.max(row * tileSize - overlap, 0)
+        const region = {
+          left,
+          top,
+          width: Math.min((col + 1) * tileSize + overlap, levelWidth) - left,
+          height: Math.min((row + 1) * tileSize + overlap, levelHeight) - top
+        }
+        await onTile(renderFlatRegion(layout, assignment, caps, levelWidth, levelHeight, region, undefined, tiles[row * cols + col]), level, col, row)
+      }
+    }
+  }
+
+  return deepZoomDescriptor(width, height, options)
+}
@@ -0,0 +1,54 @@
+import { Assignment } from '../types/Assignment'
+import { Cap } from '../types/Cap'
+import { RGB } from '../types/RGB'
+import { getCellCosts } from './colorDistance'
+
+export interface CellChange {
+  cell: number;
+  before: number | null;
+  after: number | null;
+  costBefore: number | null;
+  costAfter: number | null;
+  // Negative when the cell got better, null when it is empty before or after
+  costDelta: number | null;
+}
+
+export interface AssignmentDiff {
+  changes: Array<CellChange>;
+  // Sum of the cost deltas of cells holding a cap both before and after
+  totalCostDelta: number;
+  // Cells that held a cap before and are empty after, and the other way around
+  emptied: number;
+  filled: number;
+}
+
+// What changed between two assignments of the same layout, to show after constraints were tweaked and solved again
+export default (before: Assignment, after: Assignment, cellColors: Array<RGB>, caps: Array<Cap>): AssignmentDiff => {
+  if (before.length !== after.length) {
+    throw new Error(`Assignments of ${before.length} and ${after.length} cells are not of the same layout`)
+  }
+  const costsBefore = getCellCosts(cellColors, before, caps)
+  const costsAfter = getCellCosts(cellColors, after, caps)
+  const changes: Array<CellChange> = []
+
+  before.forEach((key, cell) => {
+    if (key === after[cell]) return
+    const costBefore = costsBefore[cell]
+    const costAfter = costsAfter[cell]
+    changes.push({
+      cell,
+      before: key,
+      after: after[cell],
+      costBefore,
+      costAfter,
+      costDelta: costBefore === null || costAfter === null ? null : costAfter - costBefore
+    })
+  })
+
+  return {
+    changes,
+    totalCostDelta: changes.reduce((total, change) => total + (change.costDelta || 0), 0),
+    emptied: changes.filter((change) => change.costBefore !== null && change.costAfter === null).length,
+    filled: changes.filter((change) => change.costBefore === null && change.costAfter !== null).length
+  }
+}
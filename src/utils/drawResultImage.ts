@@ -1,14 +1,6 @@
-import { RGB } from '../types/RGB'
+import { Cap } from '../types/Cap'
 import getAverageColor from './getAverageColor'
 
-export interface Cap {
-  key: number;
-  image: string;
-  name: string;
-  amount: number;
-  color: RGB;
-};
-
 export default async (sourceImageCanvas: HTMLCanvasElement, resultImageCanvas: HTMLCanvasElement, caps: Array<Cap>) => {
   const averageSourceImageColor = await getAverageColor(sourceImageCanvas.toDataURL())
   console.log(averageSourceImageColor)
@@ -0,0 +1,26 @@
+import { Layout, Packing } from '../types/Layout'
+import filterLayout from './filterLayout'
+import packedGrid from './packedGrid'
+
+// Rim points tested per cap, enough for caps to stick out of the ellipse by well under a tenth of a millimeter
+const RIM_POINTS = 32
+
+// Fills the ellipse inscribed in the board, a circle for square boards, keeping only caps that fit entirely.
+// Shrinking the ellipse by the cap radius is no inner offset on elongated boards, so the rim of every cap is tested
+export default (boardWidth: number, boardHeight: number, capDiameter: number, gap = 0, packing: Packing = 'hex'): Layout => {
+  const grid = packedGrid(boardWidth, boardHeight, capDiameter, gap, packing)
+  const cx = boardWidth / 2
+  const cy = boardHeight / 2
+  const a = boardWidth / 2
+  const b = boardHeight / 2
+  const isInside = (x: number, y: number) => ((x - cx) / a) ** 2 + ((y - cy) / b) ** 2 <= 1 + 1e-9
+
+  if (a < capDiameter / 2 || b < capDiameter / 2) return filterLayout(grid, () => false)
+  return filterLayout(grid, ({ x, y, diameter }) => {
+    for (let step = 0; step < RIM_POINTS; step++) {
+      const angle = step * 2 * Math.PI / RIM_POINTS
+      if (!isInside(x + diameter / 2 * Math.cos(angle), y + diameter / 2 * Math.sin(angle))) return false
+    }
+    return true
+  })
+}
@@ -0,0 +1,87 @@
+import { Assignment } from '../types/Assignment'
+import { Cap } from '../types/Cap'
+import { Layout } from '../types/Layout'
+import renderFlatCircles from './renderFlatCircles'
+import throwIfAborted from './throwIfAborted'
+
+interface Pixels {
+  data: Uint8ClampedArray;
+  width: number;
+  height: number;
+}
+
+const crcTable = Array.from({ length: 256 }, (_, n) => {
+  let c = n
+  for (let k = 0; k < 8; k++) c = c & 1 ? 0xedb88320 ^ (c >>> 1) : c >>> 1
+  return c >>> 0
+})
+
+function crc32 (bytes: Uint8Array): number {
+  let crc = 0xffffffff
+  for (let i = 0; i < bytes.length; i++) crc = crcTable[(crc ^ bytes[i]) & 0xff] ^ (crc >>> 8)
+  return (crc ^ 0xffffffff) >>> 0
+}
+
+function chunk (type: string, body: Uint8Array): Uint8Array {
+  const bytes = new Uint8Array(body.length + 12)
+  const view = new DataView(bytes.buffer)
+  view.setUint32(0, body.length)
+  for (let i = 0; i < 4; i++) bytes[4 + i] = type.charCodeAt(i)
+  bytes.set(body, 8)
+  view.setUint32(body.length + 8, crc32(bytes.subarray(4, body.length + 8)))
+  return bytes
+}
+
+// Encodes RGBA pixels as PNG without going through a canvas, which browsers limit in size.
+// Rows are compressed a batch at a time, so the uncompressed image is never copied as a whole
+export async function encodePng ({ data, width, height }: Pixels, signal?: AbortSignal): Promise<Uint8Array> {
+  const header = new Uint8Array(13)
+  const view = new DataView(header.buffer)
+  view.setUint32(0, width)
+  view.setUint32(4, height)
+  // 8 bits per channel, RGBA, default compression, filtering and no interlacing
+  header.set([8, 6, 0, 0, 0], 8)
+
+  // @ts-ignore CompressionStream is missing from this TypeScript version's DOM types
+  const stream = new CompressionStream('deflate')
+  const writer = stream.writable.getWriter()
+  const compressed = new Response(stream.readable).arrayBuffer()
+  // Aborting errors the stream, which is already reported through the abort itself
+  compressed.catch(() => undefined)
+  const rowLength = width * 4 + 1
+  const rowsPerBatch = Math.max(Math.floor((1 << 20) / rowLength), 1)
+  try {
+    for (let top = 0; top < height; top += rowsPerBatch) {
+      throwIfAborted(signal)
+      const rows = Math.min(rowsPerBatch, height - top)
+      const batch = new Uint8Array(rows * rowLength)
+      for (let row = 0; row < rows; row++) {
+        // Every scanline starts with its filter type, 0 being none
+        batch.set(data.subarray((top + row) * width * 4, (top + row + 1) * width * 4), row * rowLength + 1)
+      }
+      await writer.write(batch)
+    }
+    await writer.close()
+  } catch (error) {
+    writer.abort(error)
+    throw error
+  }
+
+  const parts = [
+    new Uint8Array([137, 80, 78, 71, 13, 10, 26, 10]),
+    chunk('IHDR', header),
+    chunk('IDAT', new Uint8Array(await compressed)),
+    chunk('IEND', new Uint8Array(0))
+  ]
+  const png = new Uint8Array(parts.reduce((length, part) => length + part.length, 0))
+  parts.reduce((offset, part) => {
+    png.set(part, offset)
+    return offset + part.length
+  }, 0)
+  return png
+}
+
+export async function renderToPng (layout: Layout, assignment: Assignment, caps: Array<Cap>, width: number, height: number, signal?: AbortSignal): Promise<Uint8Array> {
+  throwIfAborted(signal)
+  return encodePng(renderFlatCircles(layout, assignment, caps, width, height), signal)
+}
@@ -0,0 +1,84 @@
+import { Assignment } from '../types/Assignment'
+import { Cap } from '../types/Cap'
+import { Layout } from '../types/Layout'
+import createMaskTest from './createMaskTest'
+import getCapUsage from './getCapUsage'
+
+interface Options {
+  // Depth of the resin layer poured over the board in millimeters, including what fills the caps
+  resinDepth: number;
+  // Price of a single cap per cap key, caps without a price count as free
+  capPrices?: Record<number, number>;
+  resinPricePerLiter?: number;
+  boardPricePerSquareMeter?: number;
+  // Outline of the board, as for ellipseLayout or maskLayout. Without one the whole rectangle is counted
+  outline?: 'rectangle' | 'ellipse' | HTMLCanvasElement;
+  invertMask?: boolean;
+}
+
+export interface MaterialEstimate {
+  totalCaps: number;
+  caps: Array<{ key: number; name: string; used: number; shortage: number; price?: number }>;
+  // Board area in square meters
+  boardArea: number;
+  // Resin volume in milliliters
+  resinVolume: number;
+  // Only present when any price is given
+  cost?: {
+    caps: number;
+    resin: number;
+    board: number;
+    total: number;
+  };
+}
+
+const defaultOptions: Options = {
+  resinDepth: 3
+}
+
+// Board area in square millimeters, a mask being measured on a 1 mm grid
+function getBoardArea (layout: Layout, outline: Options['outline'] = 'rectangle', invert = false): number {
+  if (outline === 'rectangle') return layout.width * layout.height
+  if (outline === 'ellipse') return Math.PI * layout.width * layout.height / 4
+  const inside = createMaskTest(outline, layout.width, layout.height, invert)
+  let area = 0
+  for (let y = 0.5; y < layout.height; y++) {
+    for (let x = 0.5; x < layout.width; x++) {
+      if (inside(x, y)) area++
+    }
+  }
+  return area
+}
+
+// Everything needed to build the board, with a monetary estimate when prices are known
+export default (layout: Layout, assignment: Assignment, caps: Array<Cap>, settings: Partial<Options> = {}): MaterialEstimate => {
+  const options = { ...defaultOptions, ...settings }
+  const { usage, totalUsed } = getCapUsage(assignment, caps)
+  const area = getBoardArea(layout, options.outline, options.invertMask)
+  const boardArea = area / 1e6
+  const resinVolume = area * options.resinDepth / 1e3
+  const capPrices = options.capPrices || {}
+
+  const estimate: MaterialEstimate = {
+    totalCaps: totalUsed,
+    caps: usage
+      .filter((item) => item.used)
+      .map(({ key, name, used, shortage }) => ({
+        key,
+        name,
+        used,
+        shortage,
+        ...(capPrices[key] === undefined ? {} : { price: capPrices[key] * used })
+      })),
+    boardArea,
+    resinVolume
+  }
+
+  if (options.capPrices || options.resinPricePerLiter !== undefined || options.boardPricePerSquareMeter !== undefined) {
+    const capsCost = estimate.caps.reduce((total, item) => total + (item.price || 0), 0)
+    const resin = resinVolume / 1000 * (options.resinPricePerLiter || 0)
+    const board = boardArea * (options.boardPricePerSquareMeter || 0)
+    estimate.cost = { caps: capsCost, resin, board, total: capsCost + resin + board }
+  }
+  return estimate
+}
@@ -0,0 +1,63 @@
+import { Assignment } from '../types/Assignment'
+import { Cap } from '../types/Cap'
+import { Layout } from '../types/Layout'
+
+export function csvField (value: string | number): string {
+  const text = String(value)
+  return /[",\n\r]/.test(text) ? `"${text.replace(/"/g, '""')}"` : text
+}
+
+export function csvRow (values: Array<string | number>): string {
+  return values.map(csvField).join(',')
+}
+
+// One line per cap on the board, for the spreadsheets many builders track their progress in
+export default (assignment: Assignment, layout: Layout, caps: Array<Cap>): string => {
+  const capsByKey = new Map(caps.map((cap) => [cap.key, cap]))
+  const round = (value: number) => Math.round(value * 100) / 100
+  const lines = [csvRow(['row', 'col', 'x_mm', 'y_mm', 'cap_id', 'cap_name'])]
+
+  layout.positions.forEach(({ row, col, x, y }, cell) => {
+    const key = assignment[cell]
+    const cap = key === null ? undefined : capsByKey.get(key)
+    lines.push(csvRow([row, col, round(x), round(y), cap ? cap.key : '', cap ? cap.name : '']))
+  })
+
+  return lines.join('\n') + '\n'
+}
+
+// Splits CSV text into rows of fields, handling quoted fields with commas, quotes and line breaks in them
+export function parseCsv (text: string): Array<Array<string>> {
+  const rows: Array<Array<string>> = []
+  let row: Array<string> = []
+  let field = ''
+  let quoted = false
+
+  for (let i = 0; i < text.length; i++) {
+    const character = text[i]
+    if (quoted) {
+      if (character === '"' && text[i + 1] === '"') {
+        field += '"'
+        i++
+      } else if (character === '"') {
+        quoted = false
+      } else {
+        field += character
+      }
+    } else if (character === '"') {
+      quoted = true
+    } else if (character === ',') {
+      row.push(field)
+      field = ''
+    } else if (character === '\n' || character === '\r') {
+      if (character === '\r' && text[i + 1] === '\n') i++
+      rows.push([...row, field])
+      row = []
+      field = ''
+    } else {
+      field += character
+    }
+  }
+  if (field || row.length) rows.push([...row, field])
+  return rows
+}
@@ -0,0 +1,30 @@
+import { Layout } from '../types/Layout'
+
+// Group code and value pairs, one per line as DXF wants them
+function pairs (...values: Array<string | number>): Array<string> {
+  return values.map((value) => typeof value === 'number' ? String(Math.round(value * 1000) / 1000) : value)
+}
+
+// A DXF (R12) with a circle per cap at full scale in millimeters, for routing recesses in a table top.
+// `clearance` widens every recess so caps drop in, DXF's y axis pointing up from the bottom left of the board
+export default (layout: Layout, clearance = 0.5, outline = true): string => {
+  const lines = [
+    ...pairs('0', 'SECTION', '2', 'HEADER', '9', '$INSUNITS', '70', '4', '0', 'ENDSEC'),
+    ...pairs('0', 'SECTION', '2', 'ENTITIES')
+  ]
+
+  layout.positions.forEach(({ x, y, diameter }) => {
+    lines.push(...pairs('0', 'CIRCLE', '8', 'CAPS', '10', x, '20', layout.height - y, '30', 0, '40', diameter / 2 + clearance / 2))
+  })
+
+  if (outline) {
+    const corners = [[0, 0], [layout.width, 0], [layout.width, layout.height], [0, layout.height]]
+    corners.forEach(([x1, y1], index) => {
+      const [x2, y2] = corners[(index + 1) % corners.length]
+      lines.push(...pairs('0', 'LINE', '8', 'BOARD', '10', x1, '20', y1, '30', 0, '11', x2, '21', y2, '31', 0))
+    })
+  }
+
+  lines.push(...pairs('0', 'ENDSEC', '0', 'EOF'))
+  return lines.join('\n') + '\n'
+}
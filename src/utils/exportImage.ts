@@ -0,0 +1,31 @@
+export type ImageFormat = 'png' | 'jpeg' | 'webp'
+
+// Lossy formats keep shareable previews of a photo-realistic render small, quality being from 0 to 1
+export default (image: ImageData | HTMLCanvasElement, format: ImageFormat = 'jpeg', quality = 0.85): Promise<Blob> => {
+  let canvas = image as HTMLCanvasElement
+  if (!(image instanceof HTMLCanvasElement)) {
+    canvas = document.createElement('canvas')
+    canvas.width = image.width
+    canvas.height = image.height
+    const context = canvas.getContext('2d') as CanvasRenderingContext2D
+    // JPEG has no transparency, so whatever is transparent turns white instead of black
+    if (format === 'jpeg') {
+      context.fillStyle = '#ffffff'
+      context.fillRect(0, 0, canvas.width, canvas.height)
+    }
+    const layer = document.createElement('canvas')
+    layer.width = image.width
+    layer.height = image.height
+    ;(layer.getContext('2d') as CanvasRenderingContext2D).putImageData(image, 0, 0)
+    context.drawImage(layer, 0, 0)
+  }
+
+  // Browsers that cannot encode a format, like Safari for WebP, quietly hand back a PNG instead
+  return new Promise((resolve, reject) => canvas.toBlob(
+    (blob) => blob && blob.type === `image/${format}`
+      ? resolve(blob)
+      : reject(new Error(`This browser cannot encode the image as ${format}`)),
+    `image/${format}`,
+    quality
+  ))
+}
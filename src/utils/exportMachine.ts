@@ -0,0 +1,83 @@
+import { Assignment } from '../types/Assignment'
+import { Cap } from '../types/Cap'
+import { Layout } from '../types/Layout'
+import { AssemblyOrder, Origin } from '../types/Placement'
+import { csvRow } from './exportCsv'
+import getPlacements from './getPlacements'
+
+interface Options {
+  origin: Origin;
+  order: AssemblyOrder;
+  // In millimeters per minute
+  feed: number;
+  travelZ: number;
+  pickZ: number;
+  placeZ: number;
+  // Where caps of each type are picked up, caps without a feeder of their own come from `pickX`, `pickY`
+  pickX: number;
+  pickY: number;
+  feeders: { [capKey: number]: { x: number; y: number } };
+  // Switching the gripper or vacuum, differs per machine
+  gripCommand: string;
+  releaseCommand: string;
+}
+
+const defaults: Options = {
+  origin: 'bottomLeft',
+  order: 'color',
+  feed: 3000,
+  travelZ: 20,
+  pickZ: 0,
+  placeZ: 2,
+  pickX: -50,
+  pickY: 0,
+  feeders: {},
+  gripCommand: 'M8',
+  releaseCommand: 'M9'
+}
+
+const n = (value: number) => value.toFixed(2)
+
+// G-code that picks every cap from its feeder and puts it down at its spot, for CNC gantries and robot arms
+export function exportGcode (layout: Layout, assignment: Assignment, caps: Array<Cap>, settings: Partial<Options> = {}): string {
+  const options = { ...defaults, ...settings }
+  const lines = [
+    '; Image 2 beercaps pick and place',
+    'G21 ; millimeters',
+    'G90 ; absolute positions',
+    `G0 Z${n(options.travelZ)}`
+  ]
+
+  getPlacements(layout, assignment, caps, { origin: options.origin, order: options.order }).forEach((placement) => {
+    if (placement.capKey === null) return
+    const feeder = options.feeders[placement.capKey] || { x: options.pickX, y: options.pickY }
+    lines.push(
+      `; ${placement.step}: ${placement.capName.replace(/[\r\n;()]/g, ' ')} at row ${placement.row}, col ${placement.col}`,
+      `G0 X${n(feeder.x)} Y${n(feeder.y)}`,
+      `G1 Z${n(options.pickZ)} F${options.feed}`,
+      options.gripCommand,
+      `G0 Z${n(options.travelZ)}`,
+      `G0 X${n(placement.x)} Y${n(placement.y)}`,
+      `G1 Z${n(options.placeZ)} F${options.feed}`,
+      options.releaseCommand,
+      `G0 Z${n(options.travelZ)}`
+    )
+  })
+
+  lines.push('M2 ; end of program')
+  return lines.join('\n') + '\n'
+}
+
+// Pick and place coordinates as CSV, for machines that take a placement file instead of G-code
+export function exportPickAndPlace (layout: Layout, assignment: Assignment, caps: Array<Cap>, settings: Partial<Options> = {}): string {
+  const options = { ...defaults, ...settings }
+  const lines = [csvRow(['step', 'cap_id', 'cap_name', 'x_mm', 'y_mm', 'z_mm', 'pick_x_mm', 'pick_y_mm', 'pick_z_mm'])]
+
+  getPlacements(layout, assignment, caps, { origin: options.origin, order: options.order }).forEach((placement) => {
+    if (placement.capKey === null) return
+    const feeder = options.feeders[placement.capKey] || { x: options.pickX, y: options.pickY }
+    lines.push(csvRow([placement.step, placement.capKey, placement.capName, n(placement.x), n(placement.y), n(options.placeZ), n(feeder.x), n(feeder.y), n(options.pickZ)]))
+  })
+
+  return lines.join('\n') + '\n'
+}
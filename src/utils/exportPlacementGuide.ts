@@ -0,0 +1,120 @@
+import { Assignment } from '../types/Assignment'
+import { Cap } from '../types/Cap'
+import { Layout } from '../types/Layout'
+import getCapUsage from './getCapUsage'
+import { createPage, createPdf, MM_TO_PT, PAPER_SIZES, PaperSize, PdfPage } from './pdf'
+
+interface Options {
+  paper: PaperSize;
+  landscape: boolean;
+  // Printed size of a cap in millimeters
+  capSize: number;
+  margin: number;
+}
+
+const black = { r: 0, g: 0, b: 0 }
+const gray = { r: 120, g: 120, b: 120 }
+
+function legendLinesPerPage (paperHeight: number, margin: number): number {
+  return Math.max(Math.floor((paperHeight - 2 * margin - 16) / 6) + 1, 1)
+}
+
+// How many pages the legend of `capCount` cap types takes
+export function countLegendPages (capCount: number, paperHeight: number, margin: number): number {
+  return Math.max(Math.ceil(capCount / legendLinesPerPage(paperHeight, margin)), 1)
+}
+
+// Which number stands for which cap, as printed on the caps of the guide and the templates, continued over as many
+// pages as the cap types need. `title` gets the index of the legend page
+export function createLegendPages (assignment: Assignment, caps: Array<Cap>, paperWidth: number, paperHeight: number, margin: number, title: (page: number) => string): Array<PdfPage> {
+  const perPage = legendLinesPerPage(paperHeight, margin)
+  const usage = getCapUsage(assignment, caps).usage
+  const pages: Array<PdfPage> = []
+
+  for (let page = 0; page < countLegendPages(caps.length, paperHeight, margin); page++) {
+    const legend = createPage(paperWidth, paperHeight)
+    legend.setFill(black)
+    legend.text(margin, margin + 5, 10, title(page))
+    usage.slice(page * perPage, (page + 1) * perPage).forEach(({ name, used }, line) => {
+      const y = margin + 16 + line * 6
+      legend.text(margin + 10, y, 9, String(page * perPage + line + 1), 'right')
+      legend.text(margin + 15, y, 9, `${name || 'Unnamed cap'}, ${used} needed`)
+    })
+    pages.push(legend)
+  }
+  return pages
+}
+
+// A printable guide of the board spread over as many pages as needed. Every cap shows the number of its cap type,
+// rows and columns are labeled along the edges and the last pages list which number is which cap
+export default (layout: Layout, assignment: Assignment, caps: Array<Cap>, options = {
+  paper: 'A4',
+  landscape: false,
+  capSize: 12,
+  margin: 15
+} as Options): Uint8Array => {
+  const [paperWidth, paperHeight] = options.landscape
+    ? PAPER_SIZES[options.paper].slice().reverse()
+    : PAPER_SIZES[options.paper]
+  const header = 10
+  const areaWidth = paperWidth - 2 * options.margin
+  const areaHeight = paperHeight - 2 * options.margin - header
+  const scale = options.capSize / layout.positions.reduce((size, position) => Math.max(size, position.diameter), 1)
+  const pagesX = Math.max(Math.ceil(layout.width * scale / areaWidth), 1)
+  const pagesY = Math.max(Math.ceil(layout.height * scale / areaHeight), 1)
+  const numberOf = new Map(caps.map((cap, index) => [cap.key, index + 1]))
+  const pages: Array<PdfPage> = []
+  const totalPages = pagesX * pagesY + countLegendPages(caps.length, paperHeight, options.margin)
+
+  for (let pageY = 0; pageY < pagesY; pageY++) {
+    for (let pageX = 0; pageX < pagesX; pageX++) {
+      const page = createPage(paperWidth, paperHeight)
+      const left = pageX * areaWidth
+      const top = pageY * areaHeight
+      const toPageX = (x: number) => options.margin + x * scale - left
+      const toPageY = (y: number) => options.margin + header + y * scale - top
+      const rowLabels = new Map<number, number>()
+      const colLabels = new Map<number, Array<number>>()
+
+      page.setFill(black)
+      page.text(options.margin, options.margin + 5, 10, `Page ${pageY * pagesX + pageX + 1} of ${totalPages}, ${pageY + 1} down and ${pageX + 1} across`)
+
+      page.setLineWidth(0.2)
+      layout.positions.forEach(({ x, y, row, col, diameter }, cell) => {
+        // Caps go on the page their center is on, so no cap is printed twice
+        if (Math.min(Math.floor(x * scale / areaWidth), pagesX - 1) !== pageX) return
+        if (Math.min(Math.floor(y * scale / areaHeight), pagesY - 1) !== pageY) return
+        const cx = toPageX(x)
+        const cy = toPageY(y)
+        const r = diameter / 2 * scale
+        const key = assignment[cell]
+        const cap = key === null ? undefined : caps[(numberOf.get(key) || 0) - 1]
+
+        rowLabels.set(row, Math.min(rowLabels.get(row) || Infinity, cy))
+        colLabels.set(col, [...(colLabels.get(col) || []), cx])
+        if (cap) {
+          page.setFill(cap.color)
+          page.setStroke(gray)
+          page.circle(cx, cy, r, 'both')
+          // Dark caps get a white number
+          const light = cap.color.r * 0.299 + cap.color.g * 0.587 + cap.color.b * 0.114 > 128
+          const size = Math.max(r * 1.2, 4)
+          page.setFill(light ? black : { r: 255, g: 255, b: 255 })
+          page.text(cx, cy + size * 0.35 / MM_TO_PT, size, String(numberOf.get(cap.key)), 'center')
+        } else {
+          page.setStroke(gray)
+          page.circle(cx, cy, r)
+        }
+      })
+
+      page.setFill(gray)
+      rowLabels.forEach((y, row) => page.text(options.margin - 2, y + 1, 6, `R${row + 1}`, 'right'))
+      colLabels.forEach((xs, col) => page.text(xs.reduce((sum, x) => sum + x, 0) / xs.length, options.margin + header - 2, 6, `C${col + 1}`, 'center'))
+      pages.push(page)
+    }
+  }
+
+  pages.push(...createLegendPages(assignment, caps, paperWidth, paperHeight, options.margin, (page) => `Page ${pagesX * pagesY + page + 1} of ${totalPages}, caps`))
+
+  return createPdf(pages)
+}
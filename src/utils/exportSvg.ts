@@ -0,0 +1,47 @@
+import { Assignment } from '../types/Assignment'
+import { Cap } from '../types/Cap'
+import { Layout } from '../types/Layout'
+
+export function escapeXml (value: string): string {
+  return value.replace(/&/g, '&amp;')
+    .replace(/</g, '&lt;')
+    .replace(/>/g, '&gt;')
+    .replace(/"/g, '&quot;')
+    .replace(/'/g, '&apos;')
+}
+
+const round = (value: number) => Math.round(value * 100) / 100
+
+// One circle per cap at physical size, or a reference to the cap photo with `thumbnails`, carrying the cap
+// and its place in data attributes so designers can keep working on it in Illustrator or Inkscape
+export default (layout: Layout, assignment: Assignment, caps: Array<Cap>, thumbnails = false): string => {
+  const capsByKey = new Map(caps.map((cap) => [cap.key, cap]))
+  const symbolOf = new Map<number, string>()
+  const defs = [
+    '<clipPath id="cap-outline" clipPathUnits="objectBoundingBox"><circle cx="0.5" cy="0.5" r="0.5"/></clipPath>'
+  ]
+  if (thumbnails) {
+    caps.filter((cap) => cap.image).forEach((cap, index) => {
+      symbolOf.set(cap.key, `cap-${index}`)
+      defs.push(`<symbol id="cap-${index}" viewBox="0 0 1 1"><image width="1" height="1" xlink:href="${escapeXml(cap.image)}" clip-path="url(#cap-outline)"/></symbol>`)
+    })
+  }
+
+  const elements = layout.positions.map(({ x, y, row, col, diameter }, cell) => {
+    const key = assignment[cell]
+    const cap = key === null ? undefined : capsByKey.get(key)
+    if (!cap) return ''
+    const { r, g, b } = cap.color
+    const data = `data-cap-id="${cap.key}" data-cap-name="${escapeXml(cap.name)}" data-row="${row}" data-col="${col}" data-color="rgb(${r}, ${g}, ${b})"`
+    const symbol = symbolOf.get(cap.key)
+    return symbol
+      ? `  <use xlink:href="#${symbol}" x="${round(x - diameter / 2)}" y="${round(y - diameter / 2)}" width="${diameter}" height="${diameter}" ${data}/>`
+      : `  <circle cx="${round(x)}" cy="${round(y)}" r="${diameter / 2}" fill="rgb(${r}, ${g}, ${b})" ${data}/>`
+  }).filter((element) => element)
+
+  return '<?xml version="1.0" encoding="UTF-8"?>\n' +
+    `<svg xmlns="http://www.w3.org/2000/svg" xmlns:xlink="http://www.w3.org/1999/xlink" width="${layout.width}mm" height="${layout.height}mm" viewBox="0 0 ${layout.width} ${layout.height}">\n` +
+    `  <defs>${defs.join('')}</defs>\n` +
+    elements.join('\n') + '\n' +
+    '</svg>\n'
+}
@@ -0,0 +1,89 @@
+import { Assignment } from '../types/Assignment'
+import { Cap } from '../types/Cap'
+import { Layout } from '../types/Layout'
+import { createLegendPages } from './exportPlacementGuide'
+import { createPage, createPdf, PAPER_SIZES, PaperSize, PdfPage } from './pdf'
+
+interface Options {
+  paper: PaperSize;
+  landscape: boolean;
+  margin: number;
+  // Strip along the right and bottom of every sheet that the next sheet is glued over
+  glueTab: number;
+}
+
+const black = { r: 0, g: 0, b: 0 }
+const gray = { r: 150, g: 150, b: 150 }
+const tabColor = { r: 235, g: 235, b: 235 }
+
+// Full scale templates, every cap outlined and numbered at its exact spot, tiled over sheets of paper with crop marks
+// and glue tabs. Sheets are labeled by row letter and column number, A1 being the top left one
+export default (layout: Layout, assignment: Assignment, caps: Array<Cap>, options = {
+  paper: 'A4',
+  landscape: false,
+  margin: 10,
+  glueTab: 10
+} as Options): Uint8Array => {
+  const [paperWidth, paperHeight] = options.landscape
+    ? PAPER_SIZES[options.paper].slice().reverse()
+    : PAPER_SIZES[options.paper]
+  const { margin, glueTab } = options
+  const areaWidth = paperWidth - 2 * margin - glueTab
+  const areaHeight = paperHeight - 2 * margin - glueTab
+  const sheetsX = Math.max(Math.ceil(layout.width / areaWidth), 1)
+  const sheetsY = Math.max(Math.ceil(layout.height / areaHeight), 1)
+  const numberOf = new Map(caps.map((cap, index) => [cap.key, index + 1]))
+  const pages: Array<PdfPage> = []
+
+  for (let sheetY = 0; sheetY < sheetsY; sheetY++) {
+    for (let sheetX = 0; sheetX < sheetsX; sheetX++) {
+      const page = createPage(paperWidth, paperHeight)
+      const left = sheetX * areaWidth
+      const top = sheetY * areaHeight
+      const right = margin + areaWidth
+      const bottom = margin + areaHeight
+
+      // The glue tabs repeat what the next sheets start with, so the sheets can be lined up on it
+      page.setFill(tabColor)
+      if (sheetX < sheetsX - 1) page.rect(right, margin, glueTab, areaHeight + glueTab, 'fill')
+      if (sheetY < sheetsY - 1) page.rect(margin, bottom, areaWidth + glueTab, glueTab, 'fill')
+
+      page.setLineWidth(0.2)
+      page.setDash([1, 1])
+      layout.positions.forEach(({ x, y, diameter }, cell) => {
+        const r = diameter / 2
+        const cx = margin + x - left
+        const cy = margin + y - top
+        if (cx + r < margin || cy + r < margin || cx - r > right + glueTab || cy - r > bottom + glueTab) return
+        page.setStroke(gray)
+        page.circle(cx, cy, r)
+        const key = assignment[cell]
+        if (key === null || !numberOf.has(key)) return
+        page.setFill(black)
+        page.text(cx, cy + 1.2, 9, String(numberOf.get(key)), 'center')
+      })
+      page.setDash([])
+
+      // Crop marks just outside the corners of the part that stays visible
+      page.setStroke(black)
+      page.setLineWidth(0.3)
+      for (const [x, y, dx, dy] of [[margin, margin, -1, -1], [right, margin, 1, -1], [margin, bottom, -1, 1], [right, bottom, 1, 1]]) {
+        page.line(x + dx, y, x + dx * 6, y)
+        page.line(x, y + dy, x, y + dy * 6)
+      }
+
+      page.setFill(black)
+      const sheet = `${sheetY < 26 ? String.fromCharCode(65 + sheetY) : `${sheetY + 1}-`}${sheetX + 1}`
+      page.text(margin, margin - 3, 8, `Sheet ${sheet}, print at 100% scale, ${sheetY + 1} down and ${sheetX + 1} across`)
+      if (sheetX < sheetsX - 1) page.text(right + glueTab / 2, margin + areaHeight / 2, 7, 'glue', 'center')
+      if (sheetY < sheetsY - 1) page.text(margin + areaWidth / 2, bottom + glueTab / 2 + 1, 7, 'glue', 'center')
+      // A 50 mm line to check the printer did not scale the page
+      page.line(margin, paperHeight - margin / 2, margin + 50, paperHeight - margin / 2)
+      page.text(margin + 52, paperHeight - margin / 2 + 1, 6, '50 mm')
+      pages.push(page)
+    }
+  }
+
+  pages.push(...createLegendPages(assignment, caps, paperWidth, paperHeight, margin, (page) => page ? 'Caps, continued' : 'Caps'))
+  return createPdf(pages)
+}
@@ -0,0 +1,20 @@
+import { CapPosition, Layout } from '../types/Layout'
+
+// Keeps only the positions passing `keep`, with the neighbor lists renumbered to match
+export default (layout: Layout, keep: (position: CapPosition, index: number) => boolean): Layout => {
+  const newIndex = new Map<number, number>()
+  const positions = layout.positions.filter((position, index) => {
+    if (!keep(position, index)) return false
+    newIndex.set(index, newIndex.size)
+    return true
+  })
+
+  return {
+    width: layout.width,
+    height: layout.height,
+    positions,
+    neighbors: layout.neighbors
+      .filter((_, index) => newIndex.has(index))
+      .map((neighbors) => neighbors.filter((other) => newIndex.has(other)).map((other) => newIndex.get(other) as number))
+  }
+}
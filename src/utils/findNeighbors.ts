@@ -0,0 +1,28 @@
+import { CapPosition } from '../types/Layout'
+
+// Caps are neighbors when their rims are at most `gap` apart, give or take `tolerance` of a diameter
+export default (positions: Array<CapPosition>, gap = 0, tolerance = 0.05): Array<Array<number>> => {
+  const bucketSize = positions.reduce((size, position) => Math.max(size, position.diameter), 0) * (1 + tolerance) + gap
+  const buckets = new Map<string, Array<number>>()
+  positions.forEach((position, index) => {
+    const bucket = `${Math.floor(position.x / bucketSize)},${Math.floor(position.y / bucketSize)}`
+    buckets.set(bucket, [...(buckets.get(bucket) || []), index])
+  })
+
+  return positions.map((position, index) => {
+    const bx = Math.floor(position.x / bucketSize)
+    const by = Math.floor(position.y / bucketSize)
+    const neighbors = []
+    for (let dy = -1; dy <= 1; dy++) {
+      for (let dx = -1; dx <= 1; dx++) {
+        for (const other of buckets.get(`${bx + dx},${by + dy}`) || []) {
+          if (other === index) continue
+          const { x, y, diameter } = positions[other]
+          const reach = (position.diameter + diameter) / 2 * (1 + tolerance) + gap
+          if ((x - position.x) ** 2 + (y - position.y) ** 2 <= reach * reach) neighbors.push(other)
+        }
+      }
+    }
+    return neighbors.sort((a, b) => a - b)
+  })
+}
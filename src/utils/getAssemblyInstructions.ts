@@ -0,0 +1,63 @@
+import { Assignment } from '../types/Assignment'
+import { Cap } from '../types/Cap'
+import { Layout } from '../types/Layout'
+import { AssemblyOrder, Placement } from '../types/Placement'
+import getPlacements from './getPlacements'
+
+interface Options {
+  order: AssemblyOrder;
+  // Most caps placed in one step, a step never mixes cap types
+  batchSize: number;
+  // Every diagram draws the whole board, so these grow with steps times cells and are only meant for small boards
+  diagrams: boolean;
+}
+
+export interface AssemblyStep {
+  step: number;
+  capKey: number | null;
+  capName: string;
+  placements: Array<Placement>;
+  // Small SVG of the board, caps placed before this step in gray and those of this step in their color
+  diagram?: string;
+}
+
+function stepDiagram (layout: Layout, done: Set<number>, current: Set<number>, cap?: Cap): string {
+  const round = (value: number) => Math.round(value * 10) / 10
+  const color = cap ? `rgb(${cap.color.r}, ${cap.color.g}, ${cap.color.b})` : '#000000'
+  const circles = layout.positions.map(({ x, y, diameter }, cell) => {
+    const fill = current.has(cell) ? color : done.has(cell) ? '#cccccc' : 'none'
+    const stroke = current.has(cell) ? '#000000' : '#dddddd'
+    return `<circle cx="${round(x)}" cy="${round(y)}" r="${round(diameter / 2)}" fill="${fill}" stroke="${stroke}"/>`
+  })
+  return `<svg xmlns="http://www.w3.org/2000/svg" width="200" viewBox="0 0 ${layout.width} ${layout.height}">${circles.join('')}</svg>`
+}
+
+// Numbered steps that place caps in batches of one cap type, so builders only sort out one type at a time
+export default (layout: Layout, assignment: Assignment, caps: Array<Cap>, options = {
+  order: 'color',
+  batchSize: 20,
+  diagrams: false
+} as Options): Array<AssemblyStep> => {
+  const capsByKey = new Map(caps.map((cap) => [cap.key, cap]))
+  const steps: Array<AssemblyStep> = []
+
+  getPlacements(layout, assignment, caps, { origin: 'topLeft', order: options.order }).forEach((placement) => {
+    const last = steps[steps.length - 1]
+    if (last && last.capKey === placement.capKey && last.placements.length < options.batchSize) {
+      last.placements.push(placement)
+    } else {
+      steps.push({ step: steps.length + 1, capKey: placement.capKey, capName: placement.capName, placements: [placement] })
+    }
+  })
+
+  if (options.diagrams) {
+    const done = new Set<number>()
+    steps.forEach((step) => {
+      const current = new Set(step.placements.map((placement) => placement.cell))
+      step.diagram = stepDiagram(layout, done, current, step.capKey === null ? undefined : capsByKey.get(step.capKey))
+      current.forEach((cell) => done.add(cell))
+    })
+  }
+
+  return steps
+}
@@ -0,0 +1,34 @@
+import { Assignment } from '../types/Assignment'
+import { Layout } from '../types/Layout'
+import { AssemblyOrder } from '../types/Placement'
+
+// The cell indices of the layout in the order the caps go on the board, empty cells left out
+export default (layout: Layout, assignment: Assignment, order: AssemblyOrder = 'rows'): Array<number> => {
+  const cx = layout.width / 2
+  const cy = layout.height / 2
+  const cells = layout.positions.map((_, index) => index).filter((index) => assignment[index] !== null)
+  const byRows = (a: number, b: number) => {
+    const pa = layout.positions[a]
+    const pb = layout.positions[b]
+    return pa.row - pb.row || pa.col - pb.col || pa.y - pb.y || pa.x - pb.x
+  }
+  const bySpiral = (a: number, b: number) => {
+    const pa = layout.positions[a]
+    const pb = layout.positions[b]
+    const distance = Math.hypot(pa.x - cx, pa.y - cy) - Math.hypot(pb.x - cx, pb.y - cy)
+    return Math.abs(distance) > 1e-6
+      ? distance
+      : Math.atan2(pa.y - cy, pa.x - cx) - Math.atan2(pb.y - cy, pb.x - cx)
+  }
+
+  if (order === 'spiral') return cells.sort(bySpiral)
+  if (order === 'color') {
+    // Grouped per cap type, in the order each type first shows up row by row
+    const groupOf = new Map<number | null, number>()
+    cells.slice().sort(byRows).forEach((cell) => {
+      if (!groupOf.has(assignment[cell])) groupOf.set(assignment[cell], groupOf.size)
+    })
+    return cells.sort((a, b) => (groupOf.get(assignment[a]) as number) - (groupOf.get(assignment[b]) as number) || byRows(a, b))
+  }
+  return cells.sort(byRows)
+}
@@ -31,7 +31,7 @@ export default async (src: string, options = {
     return defaultRGB
   }
 
-  return await new Promise((resolve, reject) => {
+  return await new Promise<RGB>((resolve, reject) => {
     imgEl.onerror = reject
     imgEl.onload = () => {
       const height = canvas.height = imgEl.naturalHeight || imgEl.offsetHeight || imgEl.height
@@ -0,0 +1,50 @@
+import { Assignment } from '../types/Assignment'
+import { Cap } from '../types/Cap'
+
+export interface CapUsage {
+  key: number;
+  name: string;
+  amount: number;
+  used: number;
+  leftover: number;
+  shortage: number;
+}
+
+export interface CapUsageStatistics {
+  usage: Array<CapUsage>;
+  unused: Array<Cap>;
+  totalUsed: number;
+  totalLeftover: number;
+  totalShortage: number;
+  emptyCells: number;
+}
+
+export default (assignment: Assignment, caps: Array<Cap>): CapUsageStatistics => {
+  const usedPerKey = new Map<number, number>()
+  let emptyCells = 0
+  assignment.forEach((key) => {
+    if (key === null) emptyCells++
+    else usedPerKey.set(key, (usedPerKey.get(key) || 0) + 1)
+  })
+
+  const usage = caps.map((cap) => {
+    const used = usedPerKey.get(cap.key) || 0
+    return {
+      key: cap.key,
+      name: cap.name,
+      amount: cap.amount,
+      used,
+      leftover: Math.max(cap.amount - used, 0),
+      shortage: Math.max(used - cap.amount, 0)
+    }
+  })
+
+  return {
+    usage,
+    unused: caps.filter((cap) => !usedPerKey.has(cap.key)),
+    totalUsed: usage.reduce((total, item) => total + item.used, 0),
+    totalLeftover: usage.reduce((total, item) => total + item.leftover, 0),
+    totalShortage: usage.reduce((total, item) => total + item.shortage, 0),
+    emptyCells
+  }
+}
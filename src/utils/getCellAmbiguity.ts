@@ -0,0 +1,29 @@
+import { Assignment } from '../types/Assignment'
+import { Cap } from '../types/Cap'
+import { RGB } from '../types/RGB'
+import { rgbToLab } from './colorConversion'
+import { labDistance } from './colorDistance'
+import getCapUsage from './getCapUsage'
+import { AssignmentOptions, createCapFilter } from './greedyAssignment'
+
+// For every cell how much worse, in ΔE, the closest other cap with stock left would be. A small gap means the cap
+// can be swapped by hand without much loss, a negative one that a better cap is still available. Null for empty
+// cells and Infinity when no other cap has stock left. With the layout or allowed caps only caps that fit the slot
+// and that its stencils allow count, so the gap never points to a swap that is not allowed
+export default (cellColors: Array<RGB>, assignment: Assignment, caps: Array<Cap>, settings: Partial<Pick<AssignmentOptions, 'layout' | 'allowedCaps'>> = {}): Array<number | null> => {
+  const allowed = createCapFilter(caps, settings)
+  const { usage } = getCapUsage(assignment, caps)
+  const labs = caps.map((cap) => rgbToLab(cap.color))
+  const capIndex = new Map(caps.map((cap, index) => [cap.key, index]))
+  const available = caps.map((_, index) => index).filter((index) => usage[index].leftover > 0)
+
+  return assignment.map((key, cell) => {
+    const index = key === null ? undefined : capIndex.get(key)
+    if (index === undefined) return null
+    const lab = rgbToLab(cellColors[cell])
+    const cost = labDistance(lab, labs[index])
+    return available
+      .filter((other) => other !== index && allowed(cell, other))
+      .reduce((gap, other) => Math.min(gap, labDistance(lab, labs[other]) - cost), Infinity)
+  })
+}
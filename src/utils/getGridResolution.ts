@@ -0,0 +1,40 @@
+import { Packing } from '../types/Layout'
+
+export interface GridResolution {
+  rows: number;
+  cols: number;
+  // Caps in the shifted odd rows of a hex grid, the same as cols for square grids
+  shiftedCols: number;
+  count: number;
+  // Width divided by height of the area actually covered by caps
+  aspectRatio: number;
+}
+
+// How many caps fit along a span at the given pitch. The small tolerance keeps a board sized exactly for a number of
+// caps from losing one to rounding errors
+export function fitCount (span: number, capDiameter: number, pitch: number): number {
+  return span < capDiameter - 1e-9 ? 0 : Math.floor((span - capDiameter) / pitch + 1e-9) + 1
+}
+
+export default (boardWidth: number, boardHeight: number, capDiameter: number, gap = 0, packing: Packing = 'square'): GridResolution => {
+  const pitch = capDiameter + gap
+  const rowHeight = packing === 'hex' ? pitch * Math.sqrt(3) / 2 : pitch
+  const rows = fitCount(boardHeight, capDiameter, rowHeight)
+  const cols = fitCount(boardWidth, capDiameter, pitch)
+  // In a hex grid every odd row is shifted half a cap and may hold one cap less
+  const shiftedCols = packing === 'hex' ? fitCount(boardWidth - pitch / 2, capDiameter, pitch) : cols
+  const count = packing === 'hex'
+    ? Math.ceil(rows / 2) * cols + Math.floor(rows / 2) * shiftedCols
+    : rows * cols
+
+  const coveredWidth = cols ? (cols - 1) * pitch + capDiameter + (packing === 'hex' && rows > 1 && shiftedCols === cols ? pitch / 2 : 0) : 0
+  const coveredHeight = rows ? (rows - 1) * rowHeight + capDiameter : 0
+
+  return {
+    rows,
+    cols,
+    shiftedCols,
+    count,
+    aspectRatio: coveredHeight ? coveredWidth / coveredHeight : 0
+  }
+}
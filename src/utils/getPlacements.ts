@@ -0,0 +1,39 @@
+import { Assignment } from '../types/Assignment'
+import { Cap } from '../types/Cap'
+import { Layout } from '../types/Layout'
+import { AssemblyOrder, Origin, Placement } from '../types/Placement'
+import getAssemblyOrder from './getAssemblyOrder'
+
+interface Options {
+  origin: Origin;
+  order: AssemblyOrder;
+}
+
+// Every placed cap with its center in millimeters, numbered in assembly order
+export default (layout: Layout, assignment: Assignment, caps: Array<Cap>, options = {
+  origin: 'topLeft',
+  order: 'rows'
+} as Options): Array<Placement> => {
+  const capsByKey = new Map(caps.map((cap) => [cap.key, cap]))
+  const toOrigin = (x: number, y: number) => {
+    if (options.origin === 'center') return { x: x - layout.width / 2, y: layout.height / 2 - y }
+    if (options.origin === 'bottomLeft') return { x, y: layout.height - y }
+    return { x, y }
+  }
+
+  return getAssemblyOrder(layout, assignment, options.order).map((cell, index) => {
+    const position = layout.positions[cell]
+    const capKey = assignment[cell]
+    const cap = capKey === null ? undefined : capsByKey.get(capKey)
+    return {
+      step: index + 1,
+      cell,
+      row: position.row,
+      col: position.col,
+      ...toOrigin(position.x, position.y),
+      diameter: position.diameter,
+      capKey,
+      capName: cap ? cap.name : ''
+    }
+  })
+}
@@ -0,0 +1,72 @@
+import { Assignment } from '../types/Assignment'
+import { Cap } from '../types/Cap'
+import { Layout } from '../types/Layout'
+import { RGB } from '../types/RGB'
+import { rgbToLab } from './colorConversion'
+import { labDistance } from './colorDistance'
+import { capFitsPosition, getBaseDiameter } from './mixedDiameterLayout'
+
+export interface AssignmentOptions {
+  overstockPenalty: number;
+  // With the layout every cell only takes caps that fit its slot, see capFitsPosition
+  layout: Layout;
+  // The cap keys every cell may take, as given by getAllowedCaps for stencils
+  allowedCaps: Array<Array<number>>;
+  // The layout cells the colors are of when only part of the board is assigned, layout and allowedCaps staying
+  // indexed by layout cell
+  layoutCells: Array<number>;
+  // How many caps every cell stands for, like the cells of a symmetry orbit, taken from stock at once
+  weights: Array<number>;
+}
+
+// Whether the cap at an index of `caps` may go on a layout cell, given the slot sizes and stencils of the options
+export function createCapFilter (caps: Array<Cap>, { layout, allowedCaps }: Partial<AssignmentOptions>): (layoutCell: number, index: number) => boolean {
+  const baseDiameter = layout && getBaseDiameter(layout)
+  return (layoutCell, index) =>
+    (!layout || capFitsPosition(caps[index], layout.positions[layoutCell], baseDiameter)) &&
+    (!allowedCaps || allowedCaps[layoutCell].includes(caps[index].key))
+}
+
+// A quick approximate assignment: cells with the strongest preference for their closest cap pick first, each
+// taking the closest cap that is still in stock. When stock runs out cells take the closest cap anyway, which
+// getCapUsage then reports as shortage.
+// With an `overstockPenalty` running out is no wall: the n-th cap beyond the stock of a type costs n times the
+// penalty in extra ΔE, so a cap is only overused where it is much better than the alternatives.
+// Cells that no cap fits or is allowed on stay empty
+export default (cellColors: Array<RGB>, caps: Array<Cap>, settings: Partial<AssignmentOptions> = {}): Assignment => {
+  const { overstockPenalty, layout, allowedCaps, layoutCells, weights } = settings
+  const capLabs = caps.map((cap) => rgbToLab(cap.color))
+  const stock = caps.map((cap) => cap.amount)
+  const distances = cellColors.map((color) => {
+    const lab = rgbToLab(color)
+    return capLabs.map((capLab) => labDistance(lab, capLab))
+  })
+  const allowed = createCapFilter(caps, { layout, allowedCaps })
+  const ranked = distances.map((row, cell) => row
+    .map((_, index) => index)
+    .filter((index) => allowed(layoutCells ? layoutCells[cell] : cell, index))
+    .sort((a, b) => row[a] - row[b]))
+  const urgency = ranked.map((order, cell) => order.length > 1
+    ? distances[cell][order[1]] - distances[cell][order[0]]
+    : 0)
+  const cells = cellColors.map((_, cell) => cell).sort((a, b) => urgency[b] - urgency[a])
+  const assignment: Assignment = new Array(cellColors.length).fill(null)
+
+  cells.forEach((cell) => {
+    const order = ranked[cell]
+    if (!order.length) return
+    const weight = weights ? weights[cell] : 1
+    let chosen: number
+    if (overstockPenalty === undefined) {
+      const index = order.find((candidate) => stock[candidate] >= weight)
+      chosen = index === undefined ? order[0] : index
+    } else {
+      const penalty = overstockPenalty
+      const penalized = (candidate: number) => distances[cell][candidate] + penalty * Math.max(weight - stock[candidate], 0)
+      chosen = order.reduce((best, candidate) => penalized(candidate) < penalized(best) ? candidate : best)
+    }
+    stock[chosen] -= weight
+    assignment[cell] = caps[chosen].key
+  })
+  return assignment
+}
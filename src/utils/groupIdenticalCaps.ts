@@ -0,0 +1,39 @@
+import { Assignment } from '../types/Assignment'
+import { Cap } from '../types/Cap'
+import { deltaE } from './colorDistance'
+
+export interface CapGroup {
+  // Stands in for the whole group, holding the color of its first cap and the amount of all of them
+  cap: Cap;
+  members: Array<Cap>;
+}
+
+// Caps whose colors are at most `epsilon` ΔE apart are one and the same to an assignment, so they can be matched
+// as a single cap type with their amounts added up
+export function groupIdenticalCaps (caps: Array<Cap>, epsilon = 0): Array<CapGroup> {
+  const groups: Array<CapGroup> = []
+  caps.forEach((cap) => {
+    const group = groups.find((candidate) => (candidate.cap.diameter || 0) === (cap.diameter || 0) &&
+      deltaE(candidate.cap.color, cap.color) <= epsilon)
+    if (group) {
+      group.members.push(cap)
+      group.cap.amount += cap.amount
+    } else {
+      groups.push({ cap: { ...cap }, members: [cap] })
+    }
+  })
+  return groups
+}
+
+// Turns an assignment of group caps back into one of the original caps, using up each member's amount in turn.
+// Once all members are used up the remaining cells go to the last member
+export function expandGroupedAssignment (assignment: Assignment, groups: Array<CapGroup>): Assignment {
+  const remaining = new Map(groups.map((group) => [group.cap.key, group.members.map((member) => ({ key: member.key, left: member.amount }))]))
+  return assignment.map((key) => {
+    const members = key === null ? undefined : remaining.get(key)
+    if (!members) return key
+    const member = members.find((candidate) => candidate.left > 0) || members[members.length - 1]
+    member.left--
+    return member.key
+  })
+}
@@ -0,0 +1,121 @@
+import { Assignment } from '../types/Assignment'
+import { Cap } from '../types/Cap'
+import { GridSpec, Project } from '../types/Project'
+import { RGB } from '../types/RGB'
+import buildLayout from './buildLayout'
+import { serializeCapLibrary } from './capLibrary'
+import { parseCsv } from './exportCsv'
+import { CROWN_CAP_DIAMETER } from './mixedDiameterLayout'
+import { PROJECT_VERSION } from './project'
+
+interface Options {
+  capDiameter: number;
+  gap: number;
+}
+
+interface Tile {
+  row: number;
+  col: number;
+  value: string;
+}
+
+function parseColor (value: string): RGB | undefined {
+  const hex = value.trim().match(/^#?([0-9a-f]{6})$/i)
+  if (hex) {
+    const number = parseInt(hex[1], 16)
+    return { r: number >> 16, g: (number >> 8) & 0xff, b: number & 0xff }
+  }
+  const rgb = value.trim().match(/^rgb\(\s*(\d+)\s*,\s*(\d+)\s*,\s*(\d+)\s*\)$/i)
+  return rgb ? { r: Number(rgb[1]), g: Number(rgb[2]), b: Number(rgb[3]) } : undefined
+}
+
+// Palettes as `name,r,g,b[,amount]` or `name,#rrggbb[,amount]` lines, a header line being skipped
+export function parsePaletteCsv (csv: string): Array<Cap> {
+  return parseCsv(csv).reduce((caps: Array<Cap>, [name, ...fields]) => {
+    const color = fields.length >= 3 && fields.slice(0, 3).every((field) => /^\s*\d+\s*$/.test(field))
+      ? { r: Number(fields[0]), g: Number(fields[1]), b: Number(fields[2]) }
+      : parseColor(fields[0] || '')
+    if (!name || !color) return caps
+    const amount = Number(fields[parseColor(fields[0] || '') ? 1 : 3])
+    caps.push({ key: Math.random(), image: '', name: name.trim(), amount: amount > 0 ? Math.floor(amount) : 0, color })
+    return caps
+  }, [])
+}
+
+// Cells name a palette entry by name or key, or give a color. Unknown entries become new caps, and every cap
+// gets at least as many as the imported mosaic uses
+function toProject (tiles: Array<Tile>, palette: Array<Cap>, options: Options): Project {
+  const caps = palette.map((cap) => ({ ...cap }))
+  const find = (value: string) => caps.find((cap) => cap.name.toLowerCase() === value.toLowerCase() || String(cap.key) === value)
+  const rows = tiles.reduce((count, tile) => Math.max(count, tile.row + 1), 0)
+  const cols = tiles.reduce((count, tile) => Math.max(count, tile.col + 1), 0)
+  const assignment: Assignment = new Array(rows * cols).fill(null)
+  const used = new Map<number, number>()
+
+  tiles.forEach(({ row, col, value }) => {
+    const name = value.trim()
+    if (!name) return
+    let cap = find(name)
+    if (!cap) {
+      cap = { key: Math.random(), image: '', name, amount: 0, color: parseColor(name) || { r: 128, g: 128, b: 128 } }
+      caps.push(cap)
+    }
+    assignment[row * cols + col] = cap.key
+    used.set(cap.key, (used.get(cap.key) || 0) + 1)
+  })
+  caps.forEach((cap) => {
+    cap.amount = Math.max(cap.amount, used.get(cap.key) || 0)
+  })
+
+  const pitch = options.capDiameter + options.gap
+  const grid: GridSpec = {
+    kind: 'square',
+    boardWidth: cols ? (cols - 1) * pitch + options.capDiameter : 0,
+    boardHeight: rows ? (rows - 1) * pitch + options.capDiameter : 0,
+    capDiameter: options.capDiameter,
+    gap: options.gap
+  }
+  const cells = buildLayout(grid).positions.length
+  if (cells !== assignment.length) {
+    throw new Error(`The imported grid has ${assignment.length} cells but its layout ${cells}`)
+  }
+
+  return {
+    version: PROJECT_VERSION,
+    capLibrary: serializeCapLibrary(caps),
+    desiredRatio: cols ? rows / cols : 1,
+    grid,
+    constraints: { stencils: [] },
+    assignment
+  }
+}
+
+// A grid of rows of comma separated cells, as exported by most desktop mosaic software
+export function importGridCsv (csv: string, palette: Array<Cap> = [], options = {
+  capDiameter: CROWN_CAP_DIAMETER,
+  gap: 0
+} as Options): Project {
+  const tiles: Array<Tile> = []
+  parseCsv(csv).forEach((fields, row) => fields.forEach((value, col) => tiles.push({ row, col, value })))
+  return toProject(tiles, palette, options)
+}
+
+// XML where every element with row and col (or y and x) attributes is a tile, naming its entry with a name, tile,
+// id or color attribute
+export function importGridXml (xml: string, palette: Array<Cap> = [], options = {
+  capDiameter: CROWN_CAP_DIAMETER,
+  gap: 0
+} as Options): Project {
+  const document = new DOMParser().parseFromString(xml, 'application/xml')
+  if (document.getElementsByTagName('parsererror').length) throw new Error('Could not read the XML mosaic file')
+
+  const tiles: Array<Tile> = []
+  Array.from(document.getElementsByTagName('*')).forEach((element) => {
+    const row = element.getAttribute('row') || element.getAttribute('y')
+    const col = element.getAttribute('col') || element.getAttribute('x')
+    const value = ['name', 'tile', 'id', 'color'].map((attribute) => element.getAttribute(attribute)).find((found) => found)
+    if (row === null || col === null || !value || !/^\d+$/.test(row) || !/^\d+$/.test(col)) return
+    tiles.push({ row: Number(row), col: Number(col), value })
+  })
+  return toProject(tiles, palette, options)
+}
@@ -1,2 +1,66 @@
 export { default as getAverageColor } from './getAverageColor'
 export { default as drawResultImage } from './drawResultImage'
+export { default as getCapUsage } from './getCapUsage'
+export { default as presetCaps } from './presetCaps'
+export * from './colorConversion'
+export * from './capLibrary'
+export { default as normalizeCapImage } from './normalizeCapImage'
+export { default as findNeighbors } from './findNeighbors'
+export { default as hexGrid } from './hexGrid'
+export { default as squareGrid } from './squareGrid'
+export { default as packedGrid } from './packedGrid'
+export { default as getGridResolution, fitCount } from './getGridResolution'
+export { default as filterLayout } from './filterLayout'
+export { default as ellipseLayout } from './ellipseLayout'
+export { default as sampleCells } from './sampleCells'
+export { default as maskLayout } from './maskLayout'
+export { default as mixedDiameterLayout, CROWN_CAP_DIAMETER, capFitsPosition, getBaseDiameter } from './mixedDiameterLayout'
+export { default as quadtreeLayout } from './quadtreeLayout'
+export { default as random } from './random'
+export { default as organicLayout } from './organicLayout'
+export * from './radialLayout'
+export * from './rotatedGrid'
+export { default as getAssemblyOrder } from './getAssemblyOrder'
+export { default as getPlacements } from './getPlacements'
+export * from './splitPanels'
+export * from './symmetry'
+export { default as createMaskTest } from './createMaskTest'
+export * from './stencil'
+export { default as loadImage } from './loadImage'
+export { default as renderMosaic } from './renderMosaic'
+export { default as renderFlatCircles, renderCellColors, renderFlatRegion } from './renderFlatCircles'
+export { default as renderStrips } from './renderStrips'
+export { default as applyShading } from './applyShading'
+export * from './encodePng'
+export { default as exportImage } from './exportImage'
+export * from './deepZoom'
+export { default as exportSvg, escapeXml } from './exportSvg'
+export * from './pdf'
+export { default as exportPlacementGuide, countLegendPages, createLegendPages } from './exportPlacementGuide'
+export { default as exportCsv, csvField, csvRow, parseCsv } from './exportCsv'
+export { default as buildLayout } from './buildLayout'
+export * from './project'
+export { default as exportTemplate } from './exportTemplate'
+export { default as getAssemblyInstructions } from './getAssemblyInstructions'
+export * from './colorDistance'
+export { default as renderHeatmap } from './renderHeatmap'
+export { default as simulateColorBlindness } from './simulateColorBlindness'
+export { default as scorePlacement } from './scorePlacement'
+export { default as diffAssignments } from './diffAssignments'
+export * from './exportMachine'
+export { default as exportDxf } from './exportDxf'
+export { default as renderTimelapse } from './renderTimelapse'
+export { default as throwIfAborted } from './throwIfAborted'
+export * from './groupIdenticalCaps'
+export * from './importMosaic'
+export { default as qualityMetrics } from './qualityMetrics'
+export { default as worstCells } from './worstCells'
+export { default as greedyAssignment, createCapFilter } from './greedyAssignment'
+export { default as resolutionSweep } from './resolutionSweep'
+export { default as estimateMaterials } from './estimateMaterials'
+export { default as adjacencyStatistics } from './adjacencyStatistics'
+export { default as whatIfRemove } from './whatIfRemove'
+export { default as getCellAmbiguity } from './getCellAmbiguity'
+export { default as createDiagnostic } from './createDiagnostic'
+export { default as validateProject } from './validateProject'
+export * from './inventory'
@@ -0,0 +1,69 @@
+import { Assignment } from '../types/Assignment'
+import { Cap } from '../types/Cap'
+import { CapCounts, Inventory } from '../types/Inventory'
+
+// Bump whenever the inventory format changes, and teach importInventory to upgrade the previous version
+export const INVENTORY_VERSION = 1
+
+function countCaps (assignment: Assignment): CapCounts {
+  return assignment.reduce((counts: CapCounts, key) => {
+    if (key !== null) counts[key] = (counts[key] || 0) + 1
+    return counts
+  }, {})
+}
+
+export function createInventory (caps: Array<Cap>): Inventory {
+  return {
+    version: INVENTORY_VERSION,
+    stock: caps.reduce((stock: CapCounts, cap) => ({ ...stock, [cap.key]: cap.amount }), {}),
+    reservations: {},
+    consumed: {}
+  }
+}
+
+// Sets aside the caps of a project, replacing what it had reserved before
+export function reserveCaps (inventory: Inventory, projectId: string, assignment: Assignment): Inventory {
+  return { ...inventory, reservations: { ...inventory.reservations, [projectId]: countCaps(assignment) } }
+}
+
+export function releaseCaps (inventory: Inventory, projectId: string): Inventory {
+  const reservations = { ...inventory.reservations }
+  delete reservations[projectId]
+  return { ...inventory, reservations }
+}
+
+// Takes the reserved caps of a built project out of the stock
+export function consumeCaps (inventory: Inventory, projectId: string): Inventory {
+  const reserved = inventory.reservations[projectId] || {}
+  const stock = { ...inventory.stock }
+  const consumed = { ...(inventory.consumed[projectId] || {}) }
+  Object.keys(reserved).map(Number).forEach((key) => {
+    stock[key] = Math.max((stock[key] || 0) - reserved[key], 0)
+    consumed[key] = (consumed[key] || 0) + reserved[key]
+  })
+  return { ...releaseCaps(inventory, projectId), stock, consumed: { ...inventory.consumed, [projectId]: consumed } }
+}
+
+// The caps with their amount lowered to what is neither consumed nor reserved by other projects, to solve a new
+// board from what is left
+export function getAvailableCaps (inventory: Inventory, caps: Array<Cap>, projectId?: string): Array<Cap> {
+  return caps.map((cap) => {
+    const reserved = Object.keys(inventory.reservations)
+      .filter((id) => id !== projectId)
+      .reduce((total, id) => total + (inventory.reservations[id][cap.key] || 0), 0)
+    const stock = inventory.stock[cap.key] === undefined ? cap.amount : inventory.stock[cap.key]
+    return { ...cap, amount: Math.max(stock - reserved, 0) }
+  })
+}
+
+export function exportInventory (inventory: Inventory): string {
+  return JSON.stringify({ ...inventory, version: INVENTORY_VERSION })
+}
+
+export function importInventory (json: string): Inventory {
+  const saved = JSON.parse(json)
+  if (typeof saved.version !== 'number' || saved.version > INVENTORY_VERSION) {
+    throw new Error(`Unsupported inventory version ${saved.version}, this app reads up to version ${INVENTORY_VERSION}`)
+  }
+  return { stock: {}, reservations: {}, consumed: {}, ...saved }
+}
@@ -0,0 +1,6 @@
+export default (src: string): Promise<HTMLImageElement> => new Promise((resolve, reject) => {
+  const imgEl = document.createElement('img')
+  imgEl.onerror = reject
+  imgEl.onload = () => resolve(imgEl)
+  imgEl.src = src
+})
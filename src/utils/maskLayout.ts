@@ -0,0 +1,31 @@
+import { Layout, Packing } from '../types/Layout'
+import createMaskTest from './createMaskTest'
+import filterLayout from './filterLayout'
+import packedGrid from './packedGrid'
+
+interface Options {
+  gap: number;
+  packing: Packing;
+  // By default bright opaque pixels are inside the board
+  invert: boolean;
+}
+
+// Keeps the caps whose center and rim all fall inside the silhouette, the mask being stretched over the board
+export default (mask: HTMLCanvasElement, boardWidth: number, boardHeight: number, capDiameter: number, options = {
+  gap: 0,
+  packing: 'hex',
+  invert: false
+} as Options): Layout => {
+  const grid = packedGrid(boardWidth, boardHeight, capDiameter, options.gap, options.packing)
+  const isInside = createMaskTest(mask, boardWidth, boardHeight, options.invert)
+
+  return filterLayout(grid, ({ x, y, diameter }) => {
+    const r = diameter / 2 * 0.9
+    if (!isInside(x, y)) return false
+    for (let step = 0; step < 8; step++) {
+      const angle = step * Math.PI / 4
+      if (!isInside(x + r * Math.cos(angle), y + r * Math.sin(angle))) return false
+    }
+    return true
+  })
+}
@@ -0,0 +1,40 @@
+import { Cap } from '../types/Cap'
+import { CapPosition, Layout } from '../types/Layout'
+import findNeighbors from './findNeighbors'
+import hexGrid from './hexGrid'
+
+export const CROWN_CAP_DIAMETER = 26
+
+// The size of the ordinary slots of a layout, which caps without a diameter of their own go on
+export function getBaseDiameter (layout: Layout): number {
+  return layout.positions.reduce((smallest, position) => Math.min(smallest, position.diameter), Infinity)
+}
+
+// Whether a cap can be placed on a slot, so every size only draws from its own inventory. Caps without a
+// diameter fit the base slots of the layout, whatever size the grid was made for
+export function capFitsPosition (cap: Cap, position: CapPosition, baseDiameter = CROWN_CAP_DIAMETER): boolean {
+  return Math.abs((cap.diameter || baseDiameter) - position.diameter) < 0.5
+}
+
+// Large lids (swing-tops) spread on a coarse hex grid, the space around them filled with small caps
+export default (boardWidth: number, boardHeight: number, smallDiameter: number, largeDiameter: number, largeSpacing = largeDiameter * 3, gap = 0): Layout => {
+  const large = hexGrid(boardWidth, boardHeight, largeSpacing).positions
+    .map((position) => ({ ...position, diameter: largeDiameter }))
+  // The small caps are numbered in rows after those of the lids, so labels and row by row orders never mix the two
+  const largeRows = large.reduce((rows, lid) => Math.max(rows, lid.row + 1), 0)
+  const small = hexGrid(boardWidth, boardHeight, smallDiameter, gap).positions
+    .filter(({ x, y }) => large.every((lid) => {
+      const reach = (smallDiameter + largeDiameter) / 2 + gap
+      return (lid.x - x) ** 2 + (lid.y - y) ** 2 >= reach * reach
+    }))
+    .map((position) => ({ ...position, row: position.row + largeRows }))
+  const positions = [...large, ...small]
+
+  return {
+    width: boardWidth,
+    height: boardHeight,
+    positions,
+    // Small caps only touch the lids at a few points, so be lenient
+    neighbors: findNeighbors(positions, gap, 0.15)
+  }
+}
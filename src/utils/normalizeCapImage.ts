@@ -0,0 +1,85 @@
+import loadImage from './loadImage'
+import throwIfAborted from './throwIfAborted'
+
+interface Options {
+  size: number;
+  rotation: 0 | 90 | 180 | 270;
+  backgroundThreshold: number;
+}
+
+interface Circle {
+  cx: number;
+  cy: number;
+  r: number;
+}
+
+function rotate (imgEl: HTMLImageElement, rotation: number): HTMLCanvasElement {
+  const width = imgEl.naturalWidth || imgEl.width
+  const height = imgEl.naturalHeight || imgEl.height
+  const sideways = rotation === 90 || rotation === 270
+  const canvas = document.createElement('canvas')
+  canvas.width = sideways ? height : width
+  canvas.height = sideways ? width : height
+  const context = canvas.getContext('2d') as CanvasRenderingContext2D
+  context.translate(canvas.width / 2, canvas.height / 2)
+  context.rotate(rotation * Math.PI / 180)
+  context.drawImage(imgEl, -width / 2, -height / 2)
+  return canvas
+}
+
+// The cap is taken to be everything that differs enough from the color in the corners
+function detectCircle (canvas: HTMLCanvasElement, threshold: number): Circle {
+  const { width, height } = canvas
+  const data = (canvas.getContext('2d') as CanvasRenderingContext2D).getImageData(0, 0, width, height).data
+  const corners = [0, width - 1, (height - 1) * width, height * width - 1].map((pixel) => pixel * 4)
+  const background = [0, 1, 2].map((channel) => corners.reduce((sum, i) => sum + data[i + channel], 0) / 4)
+  let minX = width
+  let minY = height
+  let maxX = -1
+  let maxY = -1
+
+  for (let y = 0; y < height; y++) {
+    for (let x = 0; x < width; x++) {
+      const i = (y * width + x) * 4
+      const difference = data[i + 3] === 0 ? 0 : Math.abs(data[i] - background[0]) +
+        Math.abs(data[i + 1] - background[1]) +
+        Math.abs(data[i + 2] - background[2])
+      if (difference > threshold) {
+        minX = Math.min(minX, x)
+        minY = Math.min(minY, y)
+        maxX = Math.max(maxX, x)
+        maxY = Math.max(maxY, y)
+      }
+    }
+  }
+
+  if (maxX < 0) return { cx: width / 2, cy: height / 2, r: Math.min(width, height) / 2 }
+  return {
+    cx: (minX + maxX + 1) / 2,
+    cy: (minY + maxY + 1) / 2,
+    r: Math.max(maxX - minX + 1, maxY - minY + 1) / 2
+  }
+}
+
+// Rotates, centers the cap and resizes it to a square thumbnail, so every cap in the library is alike
+export default async (src: string, options = {
+  size: 100,
+  rotation: 0,
+  backgroundThreshold: 60
+} as Options, signal?: AbortSignal): Promise<string> => {
+  throwIfAborted(signal)
+  const image = await loadImage(src)
+  throwIfAborted(signal)
+  const rotated = rotate(image, options.rotation)
+  const { cx, cy, r } = detectCircle(rotated, options.backgroundThreshold)
+  const canvas = document.createElement('canvas')
+  canvas.width = canvas.height = options.size
+  const context = canvas.getContext('2d') as CanvasRenderingContext2D
+
+  context.beginPath()
+  context.arc(options.size / 2, options.size / 2, options.size / 2, 0, 2 * Math.PI)
+  context.clip()
+  context.drawImage(rotated, cx - r, cy - r, 2 * r, 2 * r, 0, 0, options.size, options.size)
+
+  return canvas.toDataURL('image/png')
+}
@@ -0,0 +1,110 @@
+import { CapPosition, Layout } from '../types/Layout'
+import findNeighbors from './findNeighbors'
+import hexGrid from './hexGrid'
+import random from './random'
+
+interface Options {
+  method: 'jitter' | 'blueNoise';
+  // Extra room around each cap as a fraction of its diameter, in which it may wander
+  jitter: number;
+  seed: number;
+  // Space kept clear between caps on top of the jitter, in millimeters
+  gap?: number;
+}
+
+function jitteredLayout (boardWidth: number, boardHeight: number, capDiameter: number, options: Options): Layout {
+  const next = random(options.seed)
+  const grid = hexGrid(boardWidth, boardHeight, capDiameter * (1 + options.jitter), options.gap || 0)
+  // Moving at most half the extra room keeps every cap clear of its neighbors and the board edge
+  const reach = capDiameter * options.jitter / 2
+
+  return {
+    ...grid,
+    positions: grid.positions.map((position) => {
+      const angle = next() * 2 * Math.PI
+      const distance = Math.sqrt(next()) * reach
+      return {
+        ...position,
+        x: position.x + distance * Math.cos(angle),
+        y: position.y + distance * Math.sin(angle),
+        diameter: capDiameter
+      }
+    })
+  }
+}
+
+// Bridson's Poisson disk sampling, caps packed at random but never overlapping
+function blueNoiseLayout (boardWidth: number, boardHeight: number, capDiameter: number, options: Options): Layout {
+  const next = random(options.seed)
+  const gap = options.gap || 0
+  const minDistance = capDiameter * (1 + options.jitter) + gap
+  const radius = capDiameter / 2
+  const innerWidth = boardWidth - capDiameter
+  const innerHeight = boardHeight - capDiameter
+  if (innerWidth < 0 || innerHeight < 0) return { width: boardWidth, height: boardHeight, positions: [], neighbors: [] }
+
+  const cellSize = minDistance / Math.SQRT2
+  const cols = Math.ceil(innerWidth / cellSize) + 1
+  const rows = Math.ceil(innerHeight / cellSize) + 1
+  const cells = new Int32Array(cols * rows).fill(-1)
+  const positions: Array<CapPosition> = []
+  const active: Array<number> = []
+
+  const add = (x: number, y: number) => {
+    cells[Math.floor(y / cellSize) * cols + Math.floor(x / cellSize)] = positions.length
+    active.push(positions.length)
+    positions.push({
+      x: x + radius,
+      y: y + radius,
+      row: Math.floor(y / capDiameter),
+      col: Math.floor(x / capDiameter),
+      diameter: capDiameter
+    })
+  }
+  const isFree = (x: number, y: number): boolean => {
+    const col = Math.floor(x / cellSize)
+    const row = Math.floor(y / cellSize)
+    for (let r = Math.max(row - 2, 0); r <= Math.min(row + 2, rows - 1); r++) {
+      for (let c = Math.max(col - 2, 0); c <= Math.min(col + 2, cols - 1); c++) {
+        const other = cells[r * cols + c]
+        if (other < 0) continue
+        const dx = positions[other].x - radius - x
+        const dy = positions[other].y - radius - y
+        if (dx * dx + dy * dy < minDistance * minDistance) return false
+      }
+    }
+    return true
+  }
+
+  add(next() * innerWidth, next() * innerHeight)
+  while (active.length) {
+    const pick = Math.floor(next() * active.length)
+    const origin = positions[active[pick]]
+    let placed = false
+    for (let attempt = 0; attempt < 30 && !placed; attempt++) {
+      const angle = next() * 2 * Math.PI
+      const distance = minDistance * (1 + next())
+      const x = origin.x - radius + distance * Math.cos(angle)
+      const y = origin.y - radius + distance * Math.sin(angle)
+      if (x < 0 || y < 0 || x > innerWidth || y > innerHeight || !isFree(x, y)) continue
+      add(x, y)
+      placed = true
+    }
+    if (!placed) active.splice(pick, 1)
+  }
+
+  return {
+    width: boardWidth,
+    height: boardHeight,
+    positions,
+    neighbors: findNeighbors(positions, gap, options.jitter + 0.3)
+  }
+}
+
+export default (boardWidth: number, boardHeight: number, capDiameter: number, options = {
+  method: 'blueNoise',
+  jitter: 0.1,
+  seed: 1
+} as Options): Layout => options.method === 'jitter'
+  ? jitteredLayout(boardWidth, boardHeight, capDiameter, options)
+  : blueNoiseLayout(boardWidth, boardHeight, capDiameter, options)
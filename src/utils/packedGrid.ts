@@ -0,0 +1,7 @@
+import { Layout, Packing } from '../types/Layout'
+import hexGrid from './hexGrid'
+import squareGrid from './squareGrid'
+
+export default (boardWidth: number, boardHeight: number, capDiameter: number, gap = 0, packing: Packing = 'hex'): Layout => packing === 'hex'
+  ? hexGrid(boardWidth, boardHeight, capDiameter, gap)
+  : squareGrid(boardWidth, boardHeight, capDiameter, gap)
@@ -0,0 +1,105 @@
+import { RGB } from '../types/RGB'
+
+export const MM_TO_PT = 72 / 25.4
+
+export type PaperSize = 'A4' | 'A3' | 'Letter'
+
+// Portrait width and height in millimeters
+export const PAPER_SIZES: { [size in PaperSize]: [number, number] } = {
+  A4: [210, 297],
+  A3: [297, 420],
+  Letter: [215.9, 279.4]
+}
+
+type PaintMode = 'fill' | 'stroke' | 'both'
+
+export interface PdfPage {
+  width: number;
+  height: number;
+  setFill: (color: RGB) => void;
+  setStroke: (color: RGB) => void;
+  setLineWidth: (width: number) => void;
+  setDash: (dash: Array<number>) => void;
+  line: (x1: number, y1: number, x2: number, y2: number) => void;
+  rect: (x: number, y: number, width: number, height: number, mode?: PaintMode) => void;
+  circle: (cx: number, cy: number, r: number, mode?: PaintMode) => void;
+  text: (x: number, y: number, size: number, value: string, align?: 'left' | 'center' | 'right') => void;
+  content: () => string;
+}
+
+const operator = { fill: 'f', stroke: 'S', both: 'B' }
+// Control point distance that makes four Bezier curves a circle
+const KAPPA = 0.5523
+const n = (value: number) => String(Math.round(value * 100) / 100)
+
+// Only latin-1 fits the standard fonts, anything else becomes a question mark
+function pdfString (value: string): string {
+  return `(${value.replace(/[^\x20-\xff]/g, '?').replace(/([\\()])/g, '\\$1')})`
+}
+
+// A page in millimeters from the top left, like the rest of the app, written out as PDF points
+export function createPage (widthMm: number, heightMm: number): PdfPage {
+  const ops: Array<string> = []
+  const px = (x: number) => n(x * MM_TO_PT)
+  const py = (y: number) => n((heightMm - y) * MM_TO_PT)
+  const color = ({ r, g, b }: RGB) => `${n(r / 255)} ${n(g / 255)} ${n(b / 255)}`
+
+  return {
+    width: widthMm,
+    height: heightMm,
+    setFill: (rgb) => ops.push(`${color(rgb)} rg`),
+    setStroke: (rgb) => ops.push(`${color(rgb)} RG`),
+    setLineWidth: (width) => ops.push(`${n(width * MM_TO_PT)} w`),
+    setDash: (dash) => ops.push(`[${dash.map((length) => n(length * MM_TO_PT)).join(' ')}] 0 d`),
+    line: (x1, y1, x2, y2) => ops.push(`${px(x1)} ${py(y1)} m ${px(x2)} ${py(y2)} l S`),
+    rect: (x, y, width, height, mode = 'stroke') => ops.push(`${px(x)} ${py(y + height)} ${n(width * MM_TO_PT)} ${n(height * MM_TO_PT)} re ${operator[mode]}`),
+    circle: (cx, cy, r, mode = 'stroke') => {
+      const k = r * KAPPA
+      ops.push([
+        `${px(cx + r)} ${py(cy)} m`,
+        `${px(cx + r)} ${py(cy + k)} ${px(cx + k)} ${py(cy + r)} ${px(cx)} ${py(cy + r)} c`,
+        `${px(cx - k)} ${py(cy + r)} ${px(cx - r)} ${py(cy + k)} ${px(cx - r)} ${py(cy)} c`,
+        `${px(cx - r)} ${py(cy - k)} ${px(cx - k)} ${py(cy - r)} ${px(cx)} ${py(cy - r)} c`,
+        `${px(cx + k)} ${py(cy - r)} ${px(cx + r)} ${py(cy - k)} ${px(cx + r)} ${py(cy)} c`,
+        operator[mode]
+      ].join(' '))
+    },
+    text: (x, y, size, value, align = 'left') => {
+      // Helvetica averages about half its size per character, close enough to align short labels
+      const width = value.length * size * 0.55 / MM_TO_PT
+      const left = align === 'center' ? x - width / 2 : align === 'right' ? x - width : x
+      ops.push(`BT /F1 ${n(size)} Tf ${px(left)} ${py(y)} Td ${pdfString(value)} Tj ET`)
+    },
+    content: () => ops.join('\n')
+  }
+}
+
+// Writes the pages out as a PDF file using the built-in Helvetica font
+export function createPdf (pages: Array<PdfPage>): Uint8Array {
+  const objects: Array<string> = [
+    '<< /Type /Catalog /Pages 2 0 R >>',
+    `<< /Type /Pages /Kids [${pages.map((_, index) => `${4 + index * 2} 0 R`).join(' ')}] /Count ${pages.length} >>`,
+    '<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica /Encoding /WinAnsiEncoding >>'
+  ]
+  pages.forEach((page, index) => {
+    const content = page.content()
+    objects.push(
+      `<< /Type /Page /Parent 2 0 R /MediaBox [0 0 ${n(page.width * MM_TO_PT)} ${n(page.height * MM_TO_PT)}] /Resources << /Font << /F1 3 0 R >> >> /Contents ${5 + index * 2} 0 R >>`,
+      `<< /Length ${content.length} >>\nstream\n${content}\nendstream`
+    )
+  })
+
+  let file = '%PDF-1.4\n'
+  const offsets = objects.map((object, index) => {
+    const offset = file.length
+    file += `${index + 1} 0 obj\n${object}\nendobj\n`
+    return offset
+  })
+  const xref = file.length
+  file += `xref\n0 ${objects.length + 1}\n0000000000 65535 f \n` +
+    offsets.map((offset) => `${String(offset).padStart(10, '0')} 00000 n \n`).join('') +
+    `trailer\n<< /Size ${objects.length + 1} /Root 1 0 R >>\nstartxref\n${xref}\n%%EOF\n`
+
+  // Every character is a single latin-1 byte, so string offsets are byte offsets
+  return Uint8Array.from(file, (character) => character.charCodeAt(0))
+}
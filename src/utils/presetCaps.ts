@@ -0,0 +1,37 @@
+import { Cap } from '../types/Cap'
+import { Lab } from '../types/Lab'
+import { labToRgb } from './colorConversion'
+
+interface PresetCap {
+  name: string;
+  color: Lab;
+}
+
+// Measured average colors of widely available caps, so a mosaic can be previewed
+// before any caps of your own are photographed
+const presets: Array<PresetCap> = [
+  { name: 'Heineken - Pils', color: { l: 40.8, a: -36.1, b: 21.1 } },
+  { name: 'Amstel - Pils', color: { l: 41.3, a: 60.7, b: 34.4 } },
+  { name: 'Grolsch - Pils', color: { l: 76.2, a: -3.4, b: -1.2 } },
+  { name: 'Hertog Jan - Pils', color: { l: 38.2, a: 51.9, b: 32.8 } },
+  { name: 'Bavaria - Pils', color: { l: 31.9, a: 17.8, b: -47.2 } },
+  { name: 'Jupiler - Pils', color: { l: 44.1, a: 60.7, b: 43.5 } },
+  { name: 'Corona Extra', color: { l: 84.3, a: -1.4, b: 16.1 } },
+  { name: 'Budweiser', color: { l: 43.3, a: 63.3, b: 40.0 } },
+  { name: 'Carlsberg', color: { l: 44.7, a: -36.3, b: 20.5 } },
+  { name: 'Guinness', color: { l: 8.8, a: 0.0, b: 0.0 } },
+  { name: 'Stella Artois', color: { l: 71.2, a: 3.2, b: 45.5 } },
+  { name: 'Leffe Blond', color: { l: 60.7, a: 7.2, b: 42.5 } },
+  { name: 'Duvel', color: { l: 92.9, a: -0.9, b: 2.4 } },
+  { name: 'La Chouffe', color: { l: 45.7, a: 2.6, b: -37.1 } },
+  { name: 'Brand - Pils', color: { l: 39.8, a: 60.1, b: 38.2 } },
+  { name: 'Desperados', color: { l: 74.0, a: -1.9, b: 62.0 } }
+]
+
+export default (amount = 100): Array<Cap> => presets.map((preset) => ({
+  key: Math.random(),
+  image: '',
+  name: preset.name,
+  amount,
+  color: labToRgb(preset.color)
+}))
@@ -0,0 +1,33 @@
+import { Project } from '../types/Project'
+
+// Bump whenever the project format changes, and teach importProject to upgrade the previous version
+export const PROJECT_VERSION = 1
+
+export function exportProject (project: Omit<Project, 'version'>): string {
+  return JSON.stringify({ ...project, version: PROJECT_VERSION })
+}
+
+// Reads any project saved so far, upgrading older formats to the current one
+export function importProject (json: string): Project {
+  const saved = JSON.parse(json)
+
+  // Save files from before projects were versioned held the cap library at the top level
+  if (Array.isArray(saved.caps)) {
+    return {
+      version: PROJECT_VERSION,
+      // Files without a cap library version get all their cap colors recomputed
+      capLibrary: { version: saved.version, caps: saved.caps },
+      uploadImage: saved.uploadImage,
+      desiredRatio: saved.desiredRatio || 1,
+      constraints: { stencils: [] }
+    }
+  }
+
+  if (typeof saved.version !== 'number' || saved.version > PROJECT_VERSION) {
+    throw new Error(`Unsupported project version ${saved.version}, this app reads up to version ${PROJECT_VERSION}`)
+  }
+  return {
+    ...saved,
+    constraints: { stencils: [], ...saved.constraints }
+  }
+}
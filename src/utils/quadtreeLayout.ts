@@ -0,0 +1,129 @@
+import { CapPosition, Layout } from '../types/Layout'
+
+interface Options {
+  minDiameter: number;
+  maxDiameter: number;
+  // Summed variance of the r, g and b channels above which a cell is split in four
+  varianceThreshold: number;
+}
+
+interface Cell {
+  x: number;
+  y: number;
+  size: number;
+}
+
+// Summed area tables, so the variance of any rectangle comes at constant cost
+function integralImages (canvas: HTMLCanvasElement): { sum: Float64Array; square: Float64Array } {
+  const { width, height } = canvas
+  const data = (canvas.getContext('2d') as CanvasRenderingContext2D).getImageData(0, 0, width, height).data
+  const stride = width + 1
+  const sum = new Float64Array(stride * (height + 1) * 3)
+  const square = new Float64Array(stride * (height + 1) * 3)
+
+  for (let y = 0; y < height; y++) {
+    for (let x = 0; x < width; x++) {
+      for (let channel = 0; channel < 3; channel++) {
+        const value = data[(y * width + x) * 4 + channel]
+        const i = ((y + 1) * stride + x + 1) * 3 + channel
+        const up = (y * stride + x + 1) * 3 + channel
+        const left = ((y + 1) * stride + x) * 3 + channel
+        const upLeft = (y * stride + x) * 3 + channel
+        sum[i] = value + sum[up] + sum[left] - sum[upLeft]
+        square[i] = value * value + square[up] + square[left] - square[upLeft]
+      }
+    }
+  }
+
+  return { sum, square }
+}
+
+// Flat regions get one large cap, detailed regions are subdivided into smaller ones
+export default (sourceImageCanvas: HTMLCanvasElement, boardWidth: number, boardHeight: number, options = {
+  minDiameter: 26,
+  maxDiameter: 104,
+  varianceThreshold: 400
+} as Options): Layout => {
+  const { width, height } = sourceImageCanvas
+  const stride = width + 1
+  const { sum, square } = integralImages(sourceImageCanvas)
+
+  const variance = ({ x, y, size }: Cell): number => {
+    const x0 = Math.min(Math.floor(x / boardWidth * width), width - 1)
+    const y0 = Math.min(Math.floor(y / boardHeight * height), height - 1)
+    const x1 = Math.max(Math.min(Math.ceil((x + size) / boardWidth * width), width), x0 + 1)
+    const y1 = Math.max(Math.min(Math.ceil((y + size) / boardHeight * height), height), y0 + 1)
+    const count = (x1 - x0) * (y1 - y0)
+    let total = 0
+    for (let channel = 0; channel < 3; channel++) {
+      const area = (table: Float64Array) => table[(y1 * stride + x1) * 3 + channel] -
+        table[(y0 * stride + x1) * 3 + channel] -
+        table[(y1 * stride + x0) * 3 + channel] +
+        table[(y0 * stride + x0) * 3 + channel]
+      const mean = area(sum) / count
+      total += area(square) / count - mean * mean
+    }
+    return total
+  }
+
+  const leaves: Array<Cell> = []
+  const split = (cell: Cell) => {
+    if (cell.x + cell.size > boardWidth + 1e-9 || cell.y + cell.size > boardHeight + 1e-9) {
+      // Cells sticking out of the board are split until they fit, or dropped
+      if (cell.size / 2 < options.minDiameter) return
+    } else if (cell.size / 2 < options.minDiameter || variance(cell) <= options.varianceThreshold) {
+      leaves.push(cell)
+      return
+    }
+    const half = cell.size / 2
+    split({ x: cell.x, y: cell.y, size: half })
+    split({ x: cell.x + half, y: cell.y, size: half })
+    split({ x: cell.x, y: cell.y + half, size: half })
+    split({ x: cell.x + half, y: cell.y + half, size: half })
+  }
+  for (let y = 0; y < boardHeight; y += options.maxDiameter) {
+    for (let x = 0; x < boardWidth; x += options.maxDiameter) {
+      split({ x, y, size: options.maxDiameter })
+    }
+  }
+
+  // Cells are neighbors when their squares share an edge, found through a grid of the smallest cells
+  const finestSize = leaves.reduce((size, cell) => Math.min(size, cell.size), options.maxDiameter)
+  const fineCols = Math.ceil(boardWidth / finestSize)
+  const fineRows = Math.ceil(boardHeight / finestSize)
+  const owner = new Int32Array(fineCols * fineRows).fill(-1)
+  const fine = (value: number) => Math.round(value / finestSize)
+  leaves.forEach((cell, index) => {
+    for (let row = fine(cell.y); row < fine(cell.y + cell.size); row++) {
+      for (let col = fine(cell.x); col < fine(cell.x + cell.size); col++) owner[row * fineCols + col] = index
+    }
+  })
+
+  const neighbors = leaves.map((cell, index) => {
+    const found = new Set<number>()
+    const visit = (row: number, col: number) => {
+      if (row < 0 || col < 0 || row >= fineRows || col >= fineCols) return
+      const other = owner[row * fineCols + col]
+      if (other >= 0 && other !== index) found.add(other)
+    }
+    for (let col = fine(cell.x); col < fine(cell.x + cell.size); col++) {
+      visit(fine(cell.y) - 1, col)
+      visit(fine(cell.y + cell.size), col)
+    }
+    for (let row = fine(cell.y); row < fine(cell.y + cell.size); row++) {
+      visit(row, fine(cell.x) - 1)
+      visit(row, fine(cell.x + cell.size))
+    }
+    return [...found].sort((a, b) => a - b)
+  })
+
+  const positions: Array<CapPosition> = leaves.map((cell) => ({
+    x: cell.x + cell.size / 2,
+    y: cell.y + cell.size / 2,
+    row: fine(cell.y),
+    col: fine(cell.x),
+    diameter: cell.size
+  }))
+
+  return { width: boardWidth, height: boardHeight, positions, neighbors }
+}
@@ -0,0 +1,68 @@
+interface QualityMetrics {
+  // Peak signal-to-noise ratio in dB over the RGB channels, Infinity for identical images
+  psnr: number;
+  // Mean structural similarity of the luma over 8 by 8 pixel windows, 1 for identical images
+  ssim: number;
+}
+
+const WINDOW = 8
+const C1 = (0.01 * 255) ** 2
+const C2 = (0.03 * 255) ** 2
+
+function luma (data: Uint8ClampedArray, i: number): number {
+  return 0.299 * data[i] + 0.587 * data[i + 1] + 0.114 * data[i + 2]
+}
+
+// Objective scores of how close a rendered mosaic is to its source image, both images having the same size.
+// Render the mosaic at the size of the source (or scale the source) before comparing
+export default (original: ImageData, rendered: ImageData): QualityMetrics => {
+  if (original.width !== rendered.width || original.height !== rendered.height) {
+    throw new Error('Images must have the same size to be compared')
+  }
+  const { width, height } = original
+  const a = original.data
+  const b = rendered.data
+
+  let squaredError = 0
+  for (let i = 0; i < a.length; i += 4) {
+    squaredError += (a[i] - b[i]) ** 2 + (a[i + 1] - b[i + 1]) ** 2 + (a[i + 2] - b[i + 2]) ** 2
+  }
+  const mse = squaredError / (width * height * 3)
+  const psnr = mse ? 10 * Math.log10(255 * 255 / mse) : Infinity
+
+  let ssim = 0
+  let windows = 0
+  for (let wy = 0; wy < height; wy += WINDOW) {
+    for (let wx = 0; wx < width; wx += WINDOW) {
+      let sumA = 0
+      let sumB = 0
+      let sumAA = 0
+      let sumBB = 0
+      let sumAB = 0
+      let count = 0
+      for (let y = wy; y < Math.min(wy + WINDOW, height); y++) {
+        for (let x = wx; x < Math.min(wx + WINDOW, width); x++) {
+          const i = (y * width + x) * 4
+          const la = luma(a, i)
+          const lb = luma(b, i)
+          sumA += la
+          sumB += lb
+          sumAA += la * la
+          sumBB += lb * lb
+          sumAB += la * lb
+          count++
+        }
+      }
+      const meanA = sumA / count
+      const meanB = sumB / count
+      const varianceA = sumAA / count - meanA * meanA
+      const varianceB = sumBB / count - meanB * meanB
+      const covariance = sumAB / count - meanA * meanB
+      ssim += ((2 * meanA * meanB + C1) * (2 * covariance + C2)) /
+        ((meanA * meanA + meanB * meanB + C1) * (varianceA + varianceB + C2))
+      windows++
+    }
+  }
+
+  return { psnr, ssim: windows ? ssim / windows : 1 }
+}
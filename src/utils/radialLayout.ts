@@ -0,0 +1,64 @@
+import { CapPosition, Layout } from '../types/Layout'
+import findNeighbors from './findNeighbors'
+
+const GOLDEN_ANGLE = Math.PI * (3 - Math.sqrt(5))
+// Smallest distance between two points of a Vogel spiral with a scale of 1
+const SPIRAL_SPACING = 1.546
+
+function toLayout (boardWidth: number, boardHeight: number, positions: Array<CapPosition>, gap: number, tolerance: number): Layout {
+  return {
+    width: boardWidth,
+    height: boardHeight,
+    positions,
+    neighbors: findNeighbors(positions, gap, tolerance)
+  }
+}
+
+// Phyllotaxis, caps following the sunflower seed pattern out from the center of the board
+export function spiralLayout (boardWidth: number, boardHeight: number, capDiameter: number, gap = 0): Layout {
+  const scale = (capDiameter + gap) / SPIRAL_SPACING
+  const maxRadius = Math.min(boardWidth, boardHeight) / 2 - capDiameter / 2
+  const positions: Array<CapPosition> = []
+
+  for (let n = 0; scale * Math.sqrt(n + 0.5) <= maxRadius; n++) {
+    const radius = scale * Math.sqrt(n + 0.5)
+    const angle = n * GOLDEN_ANGLE
+    positions.push({
+      x: boardWidth / 2 + radius * Math.cos(angle),
+      y: boardHeight / 2 + radius * Math.sin(angle),
+      row: 0,
+      col: n,
+      diameter: capDiameter
+    })
+  }
+
+  // Spiral neighbors sit somewhat further apart than the closest pair
+  return toLayout(boardWidth, boardHeight, positions, gap, 0.3)
+}
+
+// One cap in the center and rings around it, each ring a cap further out, like a clock face
+export function ringLayout (boardWidth: number, boardHeight: number, capDiameter: number, gap = 0): Layout {
+  const pitch = capDiameter + gap
+  const maxRadius = Math.min(boardWidth, boardHeight) / 2 - capDiameter / 2
+  const positions: Array<CapPosition> = []
+  if (maxRadius < 0) return toLayout(boardWidth, boardHeight, positions, gap, 0.1)
+
+  positions.push({ x: boardWidth / 2, y: boardHeight / 2, row: 0, col: 0, diameter: capDiameter })
+  for (let ring = 1; ring * pitch <= maxRadius; ring++) {
+    const radius = ring * pitch
+    // Chord between two caps on the ring must be at least one pitch
+    const count = Math.floor(Math.PI / Math.asin(Math.min(pitch / 2 / radius, 1)))
+    for (let col = 0; col < count; col++) {
+      const angle = col * 2 * Math.PI / count
+      positions.push({
+        x: boardWidth / 2 + radius * Math.cos(angle),
+        y: boardHeight / 2 + radius * Math.sin(angle),
+        row: ring,
+        col,
+        diameter: capDiameter
+      })
+    }
+  }
+
+  return toLayout(boardWidth, boardHeight, positions, gap, 0.1)
+}
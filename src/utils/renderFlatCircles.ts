@@ -0,0 +1,63 @@
+import { Assignment } from '../types/Assignment'
+import { Cap } from '../types/Cap'
+import { Layout } from '../types/Layout'
+import { RGB } from '../types/RGB'
+
+// Part of the full width by height output, in output pixels
+export interface Region {
+  left: number;
+  top: number;
+  width: number;
+  height: number;
+}
+
+// Renders a circle of the given color for every cell, leaving cells without one empty. Only the region of the
+// full width by height output is rendered, considering only `cells` when given, so large outputs can be made piece by piece
+export function renderCellColors (layout: Layout, cellColors: Array<RGB | null>, width: number, height: number, region: Region = { left: 0, top: 0, width, height }, background: RGB = { r: 250, g: 250, b: 250 }, cells?: Array<number>): ImageData {
+  const data = new Uint8ClampedArray(region.width * region.height * 4)
+  for (let i = 0; i < data.length; i += 4) {
+    data[i] = background.r
+    data[i + 1] = background.g
+    data[i + 2] = background.b
+    data[i + 3] = 255
+  }
+
+  const scaleX = width / layout.width
+  const scaleY = height / layout.height
+  // Circles are drawn round at the average scale, even when the output is stretched
+  const scale = (scaleX + scaleY) / 2
+
+  for (const cell of cells || layout.positions.keys()) {
+    const color = cellColors[cell]
+    if (!color) continue
+    const { x, y, diameter } = layout.positions[cell]
+    const cx = x * scaleX - region.left
+    const cy = y * scaleY - region.top
+    const r = diameter / 2 * scale
+
+    for (let py = Math.max(Math.floor(cy - r), 0); py < Math.min(Math.ceil(cy + r), region.height); py++) {
+      for (let px = Math.max(Math.floor(cx - r), 0); px < Math.min(Math.ceil(cx + r), region.width); px++) {
+        // Coverage of the pixel from its distance to the rim
+        const coverage = Math.min(Math.max(r - Math.hypot(px + 0.5 - cx, py + 0.5 - cy) + 0.5, 0), 1)
+        if (!coverage) continue
+        const i = (py * region.width + px) * 4
+        data[i] += (color.r - data[i]) * coverage
+        data[i + 1] += (color.g - data[i + 1]) * coverage
+        data[i + 2] += (color.b - data[i + 2]) * coverage
+      }
+    }
+  }
+
+  return new ImageData(data, region.width, region.height)
+}
+
+export function renderFlatRegion (layout: Layout, assignment: Assignment, caps: Array<Cap>, width: number, height: number, region: Region, background?: RGB, cells?: Array<number>): ImageData {
+  const colors = new Map(caps.map((cap) => [cap.key, cap.color]))
+  const cellColors = assignment.map((key) => key === null ? null : colors.get(key) || null)
+  return renderCellColors(layout, cellColors, width, height, region, background, cells)
+}
+
+// Writes anti-aliased circles of every cap's color straight into the pixels, the board stretched over width by height.
+// Much faster than canvas arc() calls once boards reach tens of thousands of caps
+export default (layout: Layout, assignment: Assignment, caps: Array<Cap>, width: number, height: number, background?: RGB): ImageData =>
+  renderFlatRegion(layout, assignment, caps, width, height, { left: 0, top: 0, width, height }, background)
@@ -0,0 +1,20 @@
+import { Assignment } from '../types/Assignment'
+import { Cap } from '../types/Cap'
+import { Layout } from '../types/Layout'
+import { RGB } from '../types/RGB'
+import { getCellCosts } from './colorDistance'
+import { renderCellColors } from './renderFlatCircles'
+
+// Green for a perfect match through yellow to red at `maxDeltaE` and beyond
+function heat (value: number): RGB {
+  const t = Math.min(Math.max(value, 0), 1)
+  return t < 0.5
+    ? { r: Math.round(510 * t), g: 200, b: 60 }
+    : { r: 255, g: Math.round(200 * (2 - 2 * t)), b: 60 }
+}
+
+// Every cap colored by how far it is from the image under it, showing where the mosaic deviates
+export default (layout: Layout, cellColors: Array<RGB>, assignment: Assignment, caps: Array<Cap>, width: number, height: number, maxDeltaE = 30): ImageData => {
+  const costs = getCellCosts(cellColors, assignment, caps)
+  return renderCellColors(layout, costs.map((cost) => cost === null ? null : heat(cost / maxDeltaE)), width, height)
+}
@@ -0,0 +1,56 @@
+import { Assignment } from '../types/Assignment'
+import { Cap } from '../types/Cap'
+import { Layout } from '../types/Layout'
+import loadImage from './loadImage'
+import throwIfAborted from './throwIfAborted'
+
+interface Options {
+  // Output pixels per millimeter of board
+  scale: number;
+  background: string;
+}
+
+// Draws the photo of every assigned cap at its position, caps without a photo as a circle of their color
+export default async (layout: Layout, assignment: Assignment, caps: Array<Cap>, options = {
+  scale: 4,
+  background: '#fafafa'
+} as Options, signal?: AbortSignal): Promise<HTMLCanvasElement> => {
+  const canvas = document.createElement('canvas')
+  canvas.width = Math.ceil(layout.width * options.scale)
+  canvas.height = Math.ceil(layout.height * options.scale)
+  const context = canvas.getContext('2d') as CanvasRenderingContext2D
+  context.fillStyle = options.background
+  context.fillRect(0, 0, canvas.width, canvas.height)
+
+  const capsByKey = new Map(caps.map((cap) => [cap.key, cap]))
+  // Every photo is decoded once, however often the cap is used
+  const thumbnails = new Map<number, HTMLImageElement | null>()
+  await Promise.all(caps.map(async (cap) => {
+    thumbnails.set(cap.key, cap.image ? await loadImage(cap.image).catch(() => null) : null)
+  }))
+  throwIfAborted(signal)
+
+  layout.positions.forEach(({ x, y, diameter }, cell) => {
+    const key = assignment[cell]
+    const cap = key === null ? undefined : capsByKey.get(key)
+    if (!cap) return
+    const cx = x * options.scale
+    const cy = y * options.scale
+    const r = diameter / 2 * options.scale
+    const thumbnail = thumbnails.get(cap.key)
+
+    context.save()
+    context.beginPath()
+    context.arc(cx, cy, r, 0, 2 * Math.PI)
+    if (thumbnail) {
+      context.clip()
+      context.drawImage(thumbnail, cx - r, cy - r, 2 * r, 2 * r)
+    } else {
+      context.fillStyle = `rgb(${cap.color.r}, ${cap.color.g}, ${cap.color.b})`
+      context.fill()
+    }
+    context.restore()
+  })
+
+  return canvas
+}
@@ -0,0 +1,29 @@
+import { Assignment } from '../types/Assignment'
+import { Cap } from '../types/Cap'
+import { Layout } from '../types/Layout'
+import { RGB } from '../types/RGB'
+import { renderFlatRegion } from './renderFlatCircles'
+import throwIfAborted from './throwIfAborted'
+
+// Renders the output in horizontal strips handed to `onStrip` one after the other, so print-sized renders can be
+// stitched or streamed to a file without ever holding the whole bitmap
+export default async (layout: Layout, assignment: Assignment, caps: Array<Cap>, width: number, height: number, onStrip: (strip: ImageData, top: number) => void | Promise<void>, stripHeight = 256, background?: RGB, signal?: AbortSignal): Promise<void> => {
+  const scaleY = height / layout.height
+  const scale = (width / layout.width + scaleY) / 2
+  const strips: Array<Array<number>> = Array.from({ length: Math.ceil(height / stripHeight) }, () => [])
+
+  // Every cap goes to all the strips it overlaps
+  layout.positions.forEach(({ y, diameter }, cell) => {
+    const r = diameter / 2 * scale
+    const first = Math.max(Math.floor((y * scaleY - r) / stripHeight), 0)
+    const last = Math.min(Math.floor((y * scaleY + r) / stripHeight), strips.length - 1)
+    for (let strip = first; strip <= last; strip++) strips[strip].push(cell)
+  })
+
+  for (let strip = 0; strip < strips.length; strip++) {
+    throwIfAborted(signal)
+    const top = strip * stripHeight
+    const region = { left: 0, top, width, height: Math.min(stripHeight, height - top) }
+    await onStrip(renderFlatRegion(layout, assignment, caps, width, height, region, background, strips[strip]), top)
+  }
+}
@@ -0,0 +1,35 @@
+import { Assignment } from '../types/Assignment'
+import { Cap } from '../types/Cap'
+import { Layout } from '../types/Layout'
+import { AssemblyOrder } from '../types/Placement'
+import getAssemblyOrder from './getAssemblyOrder'
+import { renderFlatRegion } from './renderFlatCircles'
+import throwIfAborted from './throwIfAborted'
+
+interface Options {
+  order: AssemblyOrder;
+  // Caps added between two frames
+  every: number;
+}
+
+export type FrameHandler = (frame: ImageData, index: number, placed: number) => void | Promise<void>
+
+// Frames of the board being built up in assembly order, starting empty and ending complete, for a build animation
+export default async (layout: Layout, assignment: Assignment, caps: Array<Cap>, width: number, height: number, onFrame: FrameHandler, options = {
+  order: 'rows',
+  every: 50
+} as Options, signal?: AbortSignal): Promise<number> => {
+  const order = getAssemblyOrder(layout, assignment, options.order)
+  const region = { left: 0, top: 0, width, height }
+  const every = Math.max(Math.floor(options.every), 1)
+  let index = 0
+
+  for (let placed = 0; placed < order.length + every; placed += every) {
+    throwIfAborted(signal)
+    const cells = order.slice(0, Math.min(placed, order.length))
+    await onFrame(renderFlatRegion(layout, assignment, caps, width, height, region, undefined, cells), index++, cells.length)
+    if (cells.length === order.length) break
+  }
+
+  return index
+}
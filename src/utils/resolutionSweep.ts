@@ -0,0 +1,50 @@
+import { Cap } from '../types/Cap'
+import { Packing } from '../types/Layout'
+import { getCellCosts } from './colorDistance'
+import getCapUsage from './getCapUsage'
+import greedyAssignment from './greedyAssignment'
+import packedGrid from './packedGrid'
+import sampleCells from './sampleCells'
+import throwIfAborted from './throwIfAborted'
+
+export interface ResolutionResult {
+  capCount: number;
+  rows: number;
+  cols: number;
+  boardWidth: number;
+  boardHeight: number;
+  meanDeltaE: number;
+  // Caps needed beyond what the library has
+  shortage: number;
+}
+
+// Approximate quality of the mosaic for every number of caps in `capCounts`, the board keeping the aspect ratio of
+// the image. Uses a greedy assignment, so the real result will be as good or a bit better. With an
+// `overstockPenalty` the shortage tells how many more caps the design would need instead of settling for worse ones
+export default (sourceImageCanvas: HTMLCanvasElement, caps: Array<Cap>, capCounts: Array<number>, capDiameter: number, gap = 0, packing: Packing = 'square', signal?: AbortSignal, overstockPenalty?: number): Array<ResolutionResult> => {
+  const aspectRatio = sourceImageCanvas.width / sourceImageCanvas.height
+  const pitch = capDiameter + gap
+  const rowHeight = packing === 'hex' ? pitch * Math.sqrt(3) / 2 : pitch
+
+  return capCounts.map((count) => {
+    throwIfAborted(signal)
+    const cols = Math.max(Math.round(Math.sqrt(count * aspectRatio * rowHeight / pitch)), 1)
+    const rows = Math.max(Math.round(count / cols), 1)
+    const boardWidth = (cols - 1) * pitch + capDiameter
+    const boardHeight = (rows - 1) * rowHeight + capDiameter
+    const layout = packedGrid(boardWidth, boardHeight, capDiameter, gap, packing)
+    const cellColors = sampleCells(sourceImageCanvas, layout)
+    const assignment = greedyAssignment(cellColors, caps, { overstockPenalty })
+    const costs = getCellCosts(cellColors, assignment, caps).filter((cost): cost is number => cost !== null)
+
+    return {
+      capCount: layout.positions.length,
+      rows,
+      cols,
+      boardWidth,
+      boardHeight,
+      meanDeltaE: costs.length ? costs.reduce((total, cost) => total + cost, 0) / costs.length : 0,
+      shortage: getCapUsage(assignment, caps).totalShortage
+    }
+  })
+}
@@ -0,0 +1,90 @@
+import { Layout, Packing } from '../types/Layout'
+import filterLayout from './filterLayout'
+import packedGrid from './packedGrid'
+
+interface Options {
+  gap: number;
+  packing: Packing;
+  objective: 'count' | 'edges';
+  // In degrees
+  step: number;
+}
+
+export interface RotatedGrid {
+  angle: number;
+  layout: Layout;
+}
+
+// Angle in degrees by which the grid is turned on the board, positive is clockwise on screen
+export function rotatedGrid (boardWidth: number, boardHeight: number, capDiameter: number, angle: number, gap = 0, packing: Packing = 'hex'): Layout {
+  // A grid covering the board's diagonal still covers the whole board after any rotation
+  const size = Math.ceil(Math.hypot(boardWidth, boardHeight)) + capDiameter
+  const grid = packedGrid(size, size, capDiameter, gap, packing)
+  const cos = Math.cos(angle * Math.PI / 180)
+  const sin = Math.sin(angle * Math.PI / 180)
+  const rotated = {
+    ...grid,
+    width: boardWidth,
+    height: boardHeight,
+    positions: grid.positions.map((position) => {
+      const dx = position.x - size / 2
+      const dy = position.y - size / 2
+      return {
+        ...position,
+        x: boardWidth / 2 + dx * cos - dy * sin,
+        y: boardHeight / 2 + dx * sin + dy * cos
+      }
+    })
+  }
+
+  return filterLayout(rotated, ({ x, y, diameter }) => x >= diameter / 2 && y >= diameter / 2 &&
+    x <= boardWidth - diameter / 2 && y <= boardHeight - diameter / 2)
+}
+
+// Orientation in degrees of the strongest edges in the image, from the averaged structure tensor
+export function dominantEdgeAngle (sourceImageCanvas: HTMLCanvasElement): number {
+  const { width, height } = sourceImageCanvas
+  const data = (sourceImageCanvas.getContext('2d') as CanvasRenderingContext2D).getImageData(0, 0, width, height).data
+  const luminance = (x: number, y: number) => {
+    const i = (y * width + x) * 4
+    return 0.299 * data[i] + 0.587 * data[i + 1] + 0.114 * data[i + 2]
+  }
+  let xx = 0
+  let yy = 0
+  let xy = 0
+
+  for (let y = 1; y < height - 1; y++) {
+    for (let x = 1; x < width - 1; x++) {
+      const gx = luminance(x + 1, y) - luminance(x - 1, y)
+      const gy = luminance(x, y + 1) - luminance(x, y - 1)
+      xx += gx * gx
+      yy += gy * gy
+      xy += gx * gy
+    }
+  }
+
+  // The gradient runs across the edge, the edge itself is a quarter turn further
+  return (Math.atan2(2 * xy, xx - yy) / 2 * 180 / Math.PI + 90) % 180
+}
+
+// Tries every angle within the grid's own symmetry and keeps the best one
+export function optimizeGridAngle (boardWidth: number, boardHeight: number, capDiameter: number, sourceImageCanvas?: HTMLCanvasElement, options = {
+  gap: 0,
+  packing: 'hex',
+  objective: 'count',
+  step: 1
+} as Options): RotatedGrid {
+  const symmetry = options.packing === 'hex' ? 60 : 90
+
+  if (options.objective === 'edges' && sourceImageCanvas) {
+    const angle = dominantEdgeAngle(sourceImageCanvas) % symmetry
+    return { angle, layout: rotatedGrid(boardWidth, boardHeight, capDiameter, angle, options.gap, options.packing) }
+  }
+
+  let best = { angle: 0, layout: rotatedGrid(boardWidth, boardHeight, capDiameter, 0, options.gap, options.packing) }
+  for (let angle = options.step; angle < symmetry; angle += options.step) {
+    const layout = rotatedGrid(boardWidth, boardHeight, capDiameter, angle, options.gap, options.packing)
+    if (layout.positions.length > best.layout.positions.length) best = { angle, layout }
+  }
+  return best
+}
@@ -0,0 +1,42 @@
+import { Layout } from '../types/Layout'
+import { RGB } from '../types/RGB'
+
+// Average color of the image under every cap of the layout, the image being stretched over the whole board.
+// Only the cap itself is sampled, whatever lies under the gap between caps stays out of the result
+export default (sourceImageCanvas: HTMLCanvasElement, layout: Layout): Array<RGB> => {
+  const { width, height } = sourceImageCanvas
+  const data = (sourceImageCanvas.getContext('2d') as CanvasRenderingContext2D).getImageData(0, 0, width, height).data
+  const scaleX = width / layout.width
+  const scaleY = height / layout.height
+
+  return layout.positions.map(({ x, y, diameter }) => {
+    const cx = x * scaleX
+    const cy = y * scaleY
+    const rx = Math.max(diameter / 2 * scaleX, 0.5)
+    const ry = Math.max(diameter / 2 * scaleY, 0.5)
+    const rgb = { r: 0, g: 0, b: 0 } as RGB
+    let count = 0
+
+    for (let py = Math.max(Math.floor(cy - ry), 0); py < Math.min(Math.ceil(cy + ry), height); py++) {
+      for (let px = Math.max(Math.floor(cx - rx), 0); px < Math.min(Math.ceil(cx + rx), width); px++) {
+        const dx = (px + 0.5 - cx) / rx
+        const dy = (py + 0.5 - cy) / ry
+        if (dx * dx + dy * dy > 1) continue
+        const i = (py * width + px) * 4
+        rgb.r += data[i]
+        rgb.g += data[i + 1]
+        rgb.b += data[i + 2]
+        count++
+      }
+    }
+
+    if (!count) {
+      // Caps smaller than a pixel take the color of the pixel they are on
+      const i = (Math.min(Math.floor(cy), height - 1) * width + Math.min(Math.floor(cx), width - 1)) * 4
+      return { r: data[i], g: data[i + 1], b: data[i + 2] }
+    }
+
+    // ~~ used to floor values
+    return { r: ~~(rgb.r / count), g: ~~(rgb.g / count), b: ~~(rgb.b / count) }
+  })
+}
@@ -0,0 +1,67 @@
+import { Assignment } from '../types/Assignment'
+import { Cap } from '../types/Cap'
+import { Layout } from '../types/Layout'
+import { Placement } from '../types/Placement'
+import buildLayout from './buildLayout'
+import { getCellCosts } from './colorDistance'
+import sampleCells from './sampleCells'
+
+export interface PlacementScore {
+  total: number;
+  mean: number;
+  // ΔE of every cell, null for empty cells
+  perCell: Array<number | null>;
+  // Cells holding a cap key that is not in the library, scored as empty
+  unknownCaps: Array<number>;
+}
+
+// Reads a bare assignment, a list of placements or a whole project
+function parsePlacement (placementJson: string): { assignment: Assignment; layout?: Layout } {
+  const parsed = JSON.parse(placementJson)
+  if (Array.isArray(parsed) && parsed.every((item: unknown) => item === null || typeof item === 'number')) {
+    return { assignment: parsed }
+  }
+  if (Array.isArray(parsed)) {
+    const assignment: Assignment = []
+    parsed.forEach((placement: Placement) => {
+      assignment[placement.cell] = placement.capKey
+    })
+    return { assignment: Array.from(assignment, (key) => key === undefined ? null : key) }
+  }
+  if (parsed && Array.isArray(parsed.assignment)) {
+    return { assignment: parsed.assignment, layout: parsed.grid ? buildLayout(parsed.grid) : undefined }
+  }
+  throw new Error('Not a placement, expected an assignment, a list of placements or a project')
+}
+
+// Recomputes the cost of a hand edited or imported placement, so manual edits can be compared to solved ones.
+// The layout is only needed when the placement is no project with a grid of its own
+export default (placementJson: string, sourceImageCanvas: HTMLCanvasElement, caps: Array<Cap>, layout?: Layout): PlacementScore => {
+  const placement = parsePlacement(placementJson)
+  const board = placement.layout || layout
+  if (!board) throw new Error('The placement has no grid, a layout is needed to score it')
+  if (placement.assignment.length > board.positions.length) {
+    throw new Error(`The placement has ${placement.assignment.length} cells, the layout only ${board.positions.length}`)
+  }
+
+  const keys = new Set(caps.map((cap) => cap.key))
+  const unknownCaps: Array<number> = []
+  const assignment = board.positions.map((_, cell) => {
+    const key = placement.assignment[cell]
+    if (key === undefined || key === null) return null
+    if (keys.has(key)) return key
+    unknownCaps.push(cell)
+    return null
+  })
+
+  const perCell = getCellCosts(sampleCells(sourceImageCanvas, board), assignment, caps)
+  const scored = perCell.filter((cost) => cost !== null) as Array<number>
+  const total = scored.reduce((sum, cost) => sum + cost, 0)
+
+  return {
+    total,
+    mean: scored.length ? total / scored.length : 0,
+    perCell,
+    unknownCaps
+  }
+}
@@ -0,0 +1,41 @@
+import { fromLinear, toLinear } from './colorConversion'
+
+export type ColorBlindness = 'protanopia' | 'deuteranopia' | 'tritanopia'
+
+// Machado, Oliveira and Fernandes (2009) at full severity, applied to linear RGB
+const matrices: { [type in ColorBlindness]: Array<number> } = {
+  protanopia: [
+    0.152286, 1.052583, -0.204868,
+    0.114503, 0.786281, 0.099216,
+    -0.003882, -0.048116, 1.051998
+  ],
+  deuteranopia: [
+    0.367322, 0.860646, -0.227968,
+    0.280085, 0.672501, 0.047413,
+    -0.011820, 0.042940, 0.968881
+  ],
+  tritanopia: [
+    1.255528, -0.076749, -0.178779,
+    -0.078411, 0.930809, 0.147602,
+    0.004733, 0.691367, 0.303900
+  ]
+}
+
+const linear = Array.from({ length: 256 }, (_, channel) => toLinear(channel))
+
+// A copy of the render as someone with the given color vision deficiency would see it
+export default (image: ImageData, type: ColorBlindness): ImageData => {
+  const m = matrices[type]
+  const data = new Uint8ClampedArray(image.data)
+
+  for (let i = 0; i < data.length; i += 4) {
+    const r = linear[data[i]]
+    const g = linear[data[i + 1]]
+    const b = linear[data[i + 2]]
+    data[i] = fromLinear(m[0] * r + m[1] * g + m[2] * b)
+    data[i + 1] = fromLinear(m[3] * r + m[4] * g + m[5] * b)
+    data[i + 2] = fromLinear(m[6] * r + m[7] * g + m[8] * b)
+  }
+
+  return new ImageData(data, image.width, image.height)
+}
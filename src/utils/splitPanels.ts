@@ -0,0 +1,67 @@
+import { Assignment } from '../types/Assignment'
+import { Cap } from '../types/Cap'
+import { Layout } from '../types/Layout'
+import { Placement } from '../types/Placement'
+import filterLayout from './filterLayout'
+import getCapUsage, { CapUsageStatistics } from './getCapUsage'
+
+// Bounds in millimeters follow the outer rims of the panel's own caps, so seams never cut through a cap
+export interface Panel {
+  id: number;
+  left: number;
+  top: number;
+  right: number;
+  bottom: number;
+  cells: Array<number>;
+}
+
+export interface PanelSplit {
+  panels: Array<Panel>;
+  panelOf: Array<number>;
+}
+
+// Divides the board in columns by rows panels, each cap going to the panel its center is on
+export function splitPanels (layout: Layout, columns: number, rows: number): PanelSplit {
+  const panels: Array<Panel> = []
+  for (let id = 0; id < columns * rows; id++) {
+    panels.push({ id, left: Infinity, top: Infinity, right: -Infinity, bottom: -Infinity, cells: [] })
+  }
+
+  const panelOf = layout.positions.map(({ x, y, diameter }, cell) => {
+    const col = Math.min(Math.max(Math.floor(x / layout.width * columns), 0), columns - 1)
+    const row = Math.min(Math.max(Math.floor(y / layout.height * rows), 0), rows - 1)
+    const panel = panels[row * columns + col]
+    panel.cells.push(cell)
+    panel.left = Math.min(panel.left, x - diameter / 2)
+    panel.top = Math.min(panel.top, y - diameter / 2)
+    panel.right = Math.max(panel.right, x + diameter / 2)
+    panel.bottom = Math.max(panel.bottom, y + diameter / 2)
+    return panel.id
+  })
+
+  return { panels, panelOf }
+}
+
+export function getPanelLayout (layout: Layout, split: PanelSplit, id: number): Layout {
+  const cells = new Set(split.panels[id].cells)
+  return filterLayout(layout, (_, cell) => cells.has(cell))
+}
+
+export function getPanelAssignment (assignment: Assignment, split: PanelSplit, id: number): Assignment {
+  return split.panels[id].cells.map((cell) => assignment[cell])
+}
+
+// The inventory is shared by all panels, built in order: every panel draws from what the panels before it left,
+// so its leftover is what remains for the next ones and its shortage what the box runs out of while building it
+export function getPanelUsage (assignment: Assignment, caps: Array<Cap>, split: PanelSplit): Array<CapUsageStatistics> {
+  let stock = caps
+  return split.panels.map(({ id }) => {
+    const usage = getCapUsage(getPanelAssignment(assignment, split, id), stock)
+    stock = stock.map((cap, index) => ({ ...cap, amount: usage.usage[index].leftover }))
+    return usage
+  })
+}
+
+export function tagPlacements (placements: Array<Placement>, split: PanelSplit): Array<Placement> {
+  return placements.map((placement) => ({ ...placement, panel: split.panelOf[placement.cell] }))
+}
@@ -0,0 +1,30 @@
+import { CapPosition, GridLayout } from '../types/Layout'
+import findNeighbors from './findNeighbors'
+import getGridResolution from './getGridResolution'
+
+export default (boardWidth: number, boardHeight: number, capDiameter: number, gap = 0): GridLayout => {
+  const pitch = capDiameter + gap
+  const { rows, cols } = getGridResolution(boardWidth, boardHeight, capDiameter, gap)
+  const positions: Array<CapPosition> = []
+
+  for (let row = 0; row < rows; row++) {
+    for (let col = 0; col < cols; col++) {
+      positions.push({
+        x: capDiameter / 2 + col * pitch,
+        y: capDiameter / 2 + row * pitch,
+        row,
+        col,
+        diameter: capDiameter
+      })
+    }
+  }
+
+  return {
+    width: boardWidth,
+    height: boardHeight,
+    rows,
+    cols,
+    positions,
+    neighbors: findNeighbors(positions, gap)
+  }
+}
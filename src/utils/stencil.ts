@@ -0,0 +1,29 @@
+import { Assignment } from '../types/Assignment'
+import { Cap } from '../types/Cap'
+import { Layout } from '../types/Layout'
+import createMaskTest from './createMaskTest'
+
+// A region of the board, like the letters of a word, that may only be filled from a restricted pool of caps
+export interface Stencil {
+  mask: HTMLCanvasElement;
+  capKeys: Array<number>;
+  invert?: boolean;
+}
+
+// The cap keys every cell may take, a cell inside several stencils only takes caps all of them allow
+export function getAllowedCaps (layout: Layout, caps: Array<Cap>, stencils: Array<Stencil>): Array<Array<number>> {
+  const allKeys = caps.map((cap) => cap.key)
+  const tests = stencils.map((stencil) => createMaskTest(stencil.mask, layout.width, layout.height, stencil.invert))
+
+  return layout.positions.map(({ x, y }) => stencils.reduce((allowed, stencil, index) => tests[index](x, y)
+    ? allowed.filter((key) => stencil.capKeys.includes(key))
+    : allowed, allKeys))
+}
+
+// Cells holding a cap they are not allowed to, so hand edits can be checked against the stencils
+export function findStencilViolations (assignment: Assignment, allowedCaps: Array<Array<number>>): Array<number> {
+  return assignment.reduce((violations: Array<number>, key, cell) => {
+    if (key !== null && !allowedCaps[cell].includes(key)) violations.push(cell)
+    return violations
+  }, [])
+}
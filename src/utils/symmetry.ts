@@ -0,0 +1,113 @@
+import { Assignment } from '../types/Assignment'
+import { Cap } from '../types/Cap'
+import { Layout } from '../types/Layout'
+import { RGB } from '../types/RGB'
+import greedyAssignment, { AssignmentOptions } from './greedyAssignment'
+
+export type Symmetry = 'horizontal' | 'fourFold'
+
+// Cells that must hold the same cap, the first cell of every orbit standing in for all of them
+export function getSymmetryOrbits (layout: Layout, symmetry: Symmetry): Array<Array<number>> {
+  const bucketSize = layout.positions.reduce((size, position) => Math.max(size, position.diameter), 0) || 1
+  const buckets = new Map<string, Array<number>>()
+  layout.positions.forEach(({ x, y }, index) => {
+    const bucket = `${Math.floor(x / bucketSize)},${Math.floor(y / bucketSize)}`
+    buckets.set(bucket, [...(buckets.get(bucket) || []), index])
+  })
+
+  // Grids are not always centered on the board, so the mirror image is the nearest cell within half a cap
+  const nearest = (x: number, y: number, diameter: number): number => {
+    const bx = Math.floor(x / bucketSize)
+    const by = Math.floor(y / bucketSize)
+    let best = -1
+    let bestDistance = (diameter / 2) ** 2
+    for (let dy = -1; dy <= 1; dy++) {
+      for (let dx = -1; dx <= 1; dx++) {
+        for (const other of buckets.get(`${bx + dx},${by + dy}`) || []) {
+          const distance = (layout.positions[other].x - x) ** 2 + (layout.positions[other].y - y) ** 2
+          if (distance <= bestDistance) {
+            best = other
+            bestDistance = distance
+          }
+        }
+      }
+    }
+    return best
+  }
+
+  const mirrors = (cell: number): Array<number> => {
+    const { x, y, diameter } = layout.positions[cell]
+    const mirrored = [nearest(layout.width - x, y, diameter)]
+    if (symmetry === 'fourFold') mirrored.push(nearest(x, layout.height - y, diameter), nearest(layout.width - x, layout.height - y, diameter))
+    return mirrored.filter((other) => other >= 0)
+  }
+
+  const orbitOf = new Array<number>(layout.positions.length).fill(-1)
+  const orbits: Array<Array<number>> = []
+  layout.positions.forEach((_, cell) => {
+    if (orbitOf[cell] >= 0) return
+    const orbit = [cell]
+    orbitOf[cell] = orbits.length
+    for (let i = 0; i < orbit.length; i++) {
+      for (const other of mirrors(orbit[i])) {
+        if (orbitOf[other] >= 0) continue
+        orbitOf[other] = orbits.length
+        orbit.push(other)
+      }
+    }
+    orbits.push(orbit)
+  })
+  return orbits
+}
+
+// The color every orbit should match, so only one cell per orbit needs to be assigned
+export function getOrbitColors (cellColors: Array<RGB>, orbits: Array<Array<number>>): Array<RGB> {
+  return orbits.map((orbit) => {
+    const sum = orbit.reduce((total, cell) => ({
+      r: total.r + cellColors[cell].r,
+      g: total.g + cellColors[cell].g,
+      b: total.b + cellColors[cell].b
+    }), { r: 0, g: 0, b: 0 })
+    // ~~ used to floor values
+    return { r: ~~(sum.r / orbit.length), g: ~~(sum.g / orbit.length), b: ~~(sum.b / orbit.length) }
+  })
+}
+
+// Spreads the cap chosen for every orbit over all of its cells, an orbit of n cells using n caps
+export function mirrorAssignment (orbitAssignment: Assignment, orbits: Array<Array<number>>): Assignment {
+  const assignment: Assignment = []
+  orbits.forEach((orbit, index) => orbit.forEach((cell) => {
+    assignment[cell] = orbitAssignment[index]
+  }))
+  return assignment
+}
+
+// Cells that differ from the other cells of their orbit, so a hand edited or imported assignment can be checked
+export function findSymmetryViolations (assignment: Assignment, orbits: Array<Array<number>>): Array<number> {
+  return orbits.reduce((violations: Array<number>, orbit) => {
+    orbit.forEach((cell) => {
+      if (assignment[cell] !== assignment[orbit[0]]) violations.push(cell)
+    })
+    return violations
+  }, [])
+}
+
+// greedyAssignment for a symmetric board: every orbit is assigned once and takes a cap for each of its cells from
+// stock. An orbit only takes caps that fit its first cell and that the stencils allow on all of its cells
+export function symmetricAssignment (layout: Layout, cellColors: Array<RGB>, caps: Array<Cap>, symmetry: Symmetry, settings: Partial<Pick<AssignmentOptions, 'overstockPenalty' | 'allowedCaps'>> = {}): Assignment {
+  const orbits = getSymmetryOrbits(layout, symmetry)
+  const { allowedCaps } = settings
+  const orbitAllowedCaps = allowedCaps && orbits.reduce((allowed, orbit) => {
+    allowed[orbit[0]] = orbit.reduce((keys, cell) => keys.filter((key) => allowedCaps[cell].includes(key)), allowedCaps[orbit[0]])
+    return allowed
+  }, allowedCaps.slice())
+
+  const orbitAssignment = greedyAssignment(getOrbitColors(cellColors, orbits), caps, {
+    overstockPenalty: settings.overstockPenalty,
+    layout,
+    allowedCaps: orbitAllowedCaps,
+    layoutCells: orbits.map((orbit) => orbit[0]),
+    weights: orbits.map((orbit) => orbit.length)
+  })
+  return mirrorAssignment(orbitAssignment, orbits)
+}
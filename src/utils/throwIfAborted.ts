@@ -0,0 +1,4 @@
+// Long running helpers check this between pieces of work, so they can be cancelled through an AbortController
+export default (signal?: AbortSignal): void => {
+  if (signal && signal.aborted) throw new DOMException('The operation was aborted', 'AbortError')
+}
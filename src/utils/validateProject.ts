@@ -0,0 +1,93 @@
+import { Cap } from '../types/Cap'
+import { RGB } from '../types/RGB'
+import buildLayout from './buildLayout'
+import { hashString, isCapLibraryStale } from './capLibrary'
+import { deltaE, getCellCosts } from './colorDistance'
+import getAverageColor from './getAverageColor'
+import { importProject } from './project'
+import sampleCells from './sampleCells'
+import { findSymmetryViolations, getSymmetryOrbits } from './symmetry'
+import throwIfAborted from './throwIfAborted'
+
+export interface ColorDrift {
+  key: number;
+  name: string;
+  stored: RGB;
+  recomputed: RGB;
+  deltaE: number;
+}
+
+export interface ProjectValidation {
+  valid: boolean;
+  // Saved with an older way of extracting cap colors
+  staleLibrary: boolean;
+  // Caps whose photo no longer matches the hash saved with it
+  changedImages: Array<number>;
+  // Caps whose color comes out differently when recomputed from the photo
+  colorDrift: Array<ColorDrift>;
+  // Cells of the assignment holding a cap key missing from the library
+  unknownCaps: Array<number>;
+  // Cells the saved grid has, undefined without a grid
+  layoutCells?: number;
+  assignmentCells?: number;
+  // Cells breaking the symmetry the project asks for
+  symmetryViolations: Array<number>;
+  // Total ΔE with the saved and with the recomputed cap colors, only when an image is given
+  storedCost?: number;
+  recomputedCost?: number;
+}
+
+function sum (costs: Array<number | null>): number {
+  return costs.reduce((total: number, cost) => total + (cost || 0), 0)
+}
+
+// Recomputes everything derived for a saved project and reports where it differs from what was saved, so a project
+// made with an older version can be trusted or refreshed. Colors further apart than `tolerance` ΔE count as drift
+export default async (projectJson: string, sourceImageCanvas?: HTMLCanvasElement, tolerance = 1, signal?: AbortSignal): Promise<ProjectValidation> => {
+  const project = importProject(projectJson)
+  const caps = project.capLibrary.caps || []
+  const recomputedCaps: Array<Cap> = []
+  for (const cap of caps) {
+    throwIfAborted(signal)
+    recomputedCaps.push(cap.image ? { ...cap, color: await getAverageColor(cap.image) } : cap)
+  }
+  throwIfAborted(signal)
+
+  const colorDrift = caps.reduce((drift: Array<ColorDrift>, cap, index) => {
+    const difference = deltaE(cap.color, recomputedCaps[index].color)
+    if (difference > tolerance) {
+      drift.push({ key: cap.key, name: cap.name, stored: cap.color, recomputed: recomputedCaps[index].color, deltaE: difference })
+    }
+    return drift
+  }, [])
+  const keys = new Set(caps.map((cap) => cap.key))
+  const assignment = project.assignment
+  const layout = project.grid ? buildLayout(project.grid) : undefined
+
+  const validation: ProjectValidation = {
+    valid: false,
+    staleLibrary: isCapLibraryStale(project.capLibrary),
+    changedImages: caps.filter((cap) => cap.imageHash !== hashString(cap.image)).map((cap) => cap.key),
+    colorDrift,
+    unknownCaps: (assignment || []).reduce((cells: Array<number>, key, cell) => key !== null && !keys.has(key) ? [...cells, cell] : cells, []),
+    layoutCells: layout && layout.positions.length,
+    assignmentCells: assignment && assignment.length,
+    symmetryViolations: project.constraints.symmetry && layout && assignment && assignment.length === layout.positions.length
+      ? findSymmetryViolations(assignment, getSymmetryOrbits(layout, project.constraints.symmetry))
+      : []
+  }
+
+  if (sourceImageCanvas && layout && assignment && assignment.length === layout.positions.length) {
+    const cellColors = sampleCells(sourceImageCanvas, layout)
+    validation.storedCost = sum(getCellCosts(cellColors, assignment, caps))
+    validation.recomputedCost = sum(getCellCosts(cellColors, assignment, recomputedCaps))
+  }
+
+  validation.valid = !validation.staleLibrary &&
+    !validation.changedImages.length &&
+    !colorDrift.length &&
+    !validation.unknownCaps.length &&
+    !validation.symmetryViolations.length &&
+    (!layout || !assignment || assignment.length === layout.positions.length)
+  return validation
+}
@@ -0,0 +1,76 @@
+import { Assignment } from '../types/Assignment'
+import { Cap } from '../types/Cap'
+import { RGB } from '../types/RGB'
+import { getCellCosts } from './colorDistance'
+import getCapUsage from './getCapUsage'
+import greedyAssignment, { AssignmentOptions } from './greedyAssignment'
+
+interface Options extends Pick<AssignmentOptions, 'layout' | 'allowedCaps'> {
+  // Solve the whole board with and without the cap instead of only refilling its cells
+  resolve: boolean;
+}
+
+export interface WhatIfRemoval {
+  // Cells that held the removed cap
+  cells: Array<number>;
+  // The assignment without the removed cap
+  assignment: Assignment;
+  // Cells no other cap fits or is allowed on, left empty and out of the totals
+  unfillable: Array<number>;
+  // Total ΔE before and after over the cells filled in both
+  totalBefore: number;
+  totalAfter: number;
+  increase: number;
+  // Caps used beyond the stock of another cap type
+  shortage: number;
+}
+
+// The cost of doing without a cap type. By default only the cells holding it are refilled from the leftover
+// stock, the rest of the mosaic staying as it is, so this is an upper bound on what a full new solve would lose.
+// With `resolve` greedyAssignment solves the whole board with and without the cap, and the two are compared
+// instead of `assignment`, so the solver that made it does not blur the difference
+export default (cellColors: Array<RGB>, assignment: Assignment, caps: Array<Cap>, capKey: number, settings: Partial<Options> = {}): WhatIfRemoval => {
+  const { resolve, layout, allowedCaps } = settings
+  const remaining = caps.filter((cap) => cap.key !== capKey)
+  const before = resolve ? greedyAssignment(cellColors, caps, { layout, allowedCaps }) : assignment
+  const cells = before.reduce((found: Array<number>, key, cell) => key === capKey ? [...found, cell] : found, [])
+
+  let after: Assignment
+  if (resolve) {
+    after = greedyAssignment(cellColors, remaining, { layout, allowedCaps })
+  } else {
+    const { usage } = getCapUsage(assignment, caps)
+    const leftover = caps
+      .map((cap, index) => ({ ...cap, amount: usage[index].leftover }))
+      .filter((cap) => cap.key !== capKey)
+    const refill = greedyAssignment(cells.map((cell) => cellColors[cell]), leftover, { layout, allowedCaps, layoutCells: cells })
+
+    after = assignment.slice()
+    cells.forEach((cell, index) => {
+      after[cell] = refill[index]
+    })
+  }
+
+  const costsBefore = getCellCosts(cellColors, before, caps)
+  const costsAfter = getCellCosts(cellColors, after, remaining)
+  const unfillable: Array<number> = []
+  let totalBefore = 0
+  let totalAfter = 0
+  costsBefore.forEach((cost, cell) => {
+    const costAfter = costsAfter[cell]
+    if (cost !== null && costAfter === null) unfillable.push(cell)
+    if (cost === null || costAfter === null) return
+    totalBefore += cost
+    totalAfter += costAfter
+  })
+
+  return {
+    cells,
+    assignment: after,
+    unfillable,
+    totalBefore,
+    totalAfter,
+    increase: totalAfter - totalBefore,
+    shortage: getCapUsage(after, remaining).totalShortage
+  }
+}
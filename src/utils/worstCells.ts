@@ -0,0 +1,46 @@
+import { Assignment } from '../types/Assignment'
+import { Cap } from '../types/Cap'
+import { Layout } from '../types/Layout'
+import { RGB } from '../types/RGB'
+import { rgbToLab } from './colorConversion'
+import { labDistance } from './colorDistance'
+
+export interface WorstCell {
+  cell: number;
+  row: number;
+  col: number;
+  x: number;
+  y: number;
+  cap: Cap;
+  deltaE: number;
+  // Closest cap in the library regardless of stock, undefined when the library has no other cap
+  alternative?: Cap;
+  alternativeDeltaE?: number;
+}
+
+// The `count` cells matching their image color the worst, worst first, to see which caps are worth sourcing
+export default (layout: Layout, cellColors: Array<RGB>, assignment: Assignment, caps: Array<Cap>, count = 10): Array<WorstCell> => {
+  const labs = caps.map((cap) => rgbToLab(cap.color))
+  const capIndex = new Map(caps.map((cap, index) => [cap.key, index]))
+
+  return assignment
+    .reduce((cells: Array<WorstCell>, key, cell) => {
+      const index = key === null ? undefined : capIndex.get(key)
+      if (index === undefined) return cells
+      const lab = rgbToLab(cellColors[cell])
+      const { x, y, row, col } = layout.positions[cell]
+      const worstCell: WorstCell = { cell, row, col, x, y, cap: caps[index], deltaE: labDistance(lab, labs[index]) }
+      labs.forEach((capLab, other) => {
+        if (other === index) return
+        const distance = labDistance(lab, capLab)
+        if (worstCell.alternativeDeltaE === undefined || distance < worstCell.alternativeDeltaE) {
+          worstCell.alternative = caps[other]
+          worstCell.alternativeDeltaE = distance
+        }
+      })
+      cells.push(worstCell)
+      return cells
+    }, [])
+    .sort((a, b) => b.deltaE - a.deltaE)
+    .slice(0, count)
+}